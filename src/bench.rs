@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use crate::constraints::Difficulty;
+use crate::feedback::Feedback;
+use crate::round::{Round, UNLIMITED_TRIES};
+use crate::solver;
+use crate::tree::DecisionTree;
+
+// The names accepted by "--strategy".
+pub const STRATEGIES: &[&str] = &["entropy", "first", "random", "tree"];
+
+// A way of choosing the next guess from the history of a game.
+// Returns "None" when the strategy has nothing to suggest.
+pub trait Strategy {
+    fn next_guess(&mut self, history: &[(String, Feedback)]) -> Option<String>;
+}
+
+// To follow a decision tree, e.g. one built greedily by expected information or loaded from a file.
+pub struct TreeStrategy {
+    tree: DecisionTree,
+}
+
+impl TreeStrategy {
+    pub fn new(tree: DecisionTree) -> Self {
+        Self { tree }
+    }
+
+    // The greedy entropy strategy: the same choice is made for the same history,
+    // so the whole strategy is built once as a tree instead of ranking guesses in every game.
+    pub fn entropy(opener: Option<&str>, answers: &[String], acceptable: &[String], difficulty: Difficulty) -> Self {
+        let opener = match opener {
+            Some(opener) => opener.to_string(),
+            None => solver::rank_guesses(acceptable, answers)[0].word.clone(),
+        };
+        Self::new(DecisionTree::build(&opener, answers, acceptable, difficulty))
+    }
+}
+
+impl Strategy for TreeStrategy {
+    fn next_guess(&mut self, history: &[(String, Feedback)]) -> Option<String> {
+        self.tree.recommendations(history).get(history.len()).cloned()
+    }
+}
+
+// To guess the first answer that is still possible.
+pub struct FirstStrategy {
+    answers: Vec<String>,
+}
+
+impl FirstStrategy {
+    pub fn new(answers: &[String]) -> Self {
+        Self { answers: answers.to_vec() }
+    }
+}
+
+impl Strategy for FirstStrategy {
+    fn next_guess(&mut self, history: &[(String, Feedback)]) -> Option<String> {
+        self.answers.iter().find(|word| solver::is_consistent(word, history)).cloned()
+    }
+}
+
+// To guess a random answer that is still possible, drawn from a seeded generator.
+pub struct RandomStrategy {
+    answers: Vec<String>,
+    rng: StdRng,
+}
+
+impl RandomStrategy {
+    pub fn new(answers: &[String], seed: u64) -> Self {
+        Self { answers: answers.to_vec(), rng: StdRng::seed_from_u64(seed) }
+    }
+}
+
+impl Strategy for RandomStrategy {
+    fn next_guess(&mut self, history: &[(String, Feedback)]) -> Option<String> {
+        let candidates = solver::filter_candidates(&self.answers, history);
+        candidates.choose(&mut self.rng).cloned()
+    }
+}
+
+// The result of playing a strategy against every answer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BenchReport {
+    pub games: usize,
+    pub wins: usize,
+    pub total_guesses: usize,
+    // The number of games won with each number of guesses.
+    pub histogram: BTreeMap<usize, usize>,
+    pub failed: Vec<String>,
+}
+
+impl BenchReport {
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 { 0.0 } else { self.wins as f64 / self.games as f64 }
+    }
+
+    // The mean number of guesses over the games won.
+    pub fn mean_guesses(&self) -> f64 {
+        if self.wins == 0 { 0.0 } else { self.total_guesses as f64 / self.wins as f64 }
+    }
+}
+
+// To play a single game under the rules of a "Round".
+// A game is lost when the strategy gives up or suggests a guess the rules reject.
+// With unlimited tries, a strategy repeating itself would never finish, so the game is lost
+// once it has used as many guesses as there are acceptable words.
+pub fn play(strategy: &mut dyn Strategy, answer: &str, acceptable: &[String], difficulty: Difficulty, max_tries: usize) -> Round {
+    let mut round = Round::new(answer, difficulty, max_tries);
+    while !round.is_over() {
+        if max_tries == UNLIMITED_TRIES && round.tries() >= acceptable.len() {
+            break;
+        }
+        let Some(guess) = strategy.next_guess(round.history()) else { break };
+        if round.check(&guess, acceptable).is_err() {
+            break;
+        }
+        round.play(&guess);
+    }
+    round
+}
+
+// To play the strategy against every answer, in order.
+pub fn run(strategy: &mut dyn Strategy, answers: &[String], acceptable: &[String], difficulty: Difficulty, max_tries: usize) -> BenchReport {
+    let mut report = BenchReport::default();
+    for answer in answers {
+        let round = play(strategy, answer, acceptable, difficulty, max_tries);
+        report.games += 1;
+        if round.is_won() {
+            report.wins += 1;
+            report.total_guesses += round.tries();
+            *report.histogram.entry(round.tries()).or_insert(0) += 1;
+        } else {
+            report.failed.push(answer.clone());
+        }
+    }
+    report
+}
@@ -0,0 +1,145 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use crate::feedback::{Feedback, TileState};
+
+// To describe how strictly the revealed hints must be used.
+// "Hard" follows the official game: greens must stay in place and yellows must be reused.
+// "UltraHard" also forbids grey letters and yellow letters in a position they were yellow in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Difficulty {
+    Normal,
+    Hard,
+    UltraHard,
+}
+
+// To describe why a guess breaks the rules, e.g. "4th letter must be T".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    MissingGreen { position: usize, letter: char },
+    MissingLetter { letter: char, count: usize },
+    GreyLetter { letter: char, count: usize },
+    RepeatedYellow { position: usize, letter: char },
+}
+
+// To turn a 0-based position into "1st", "2nd", "3rd", ...
+fn ordinal(position: usize) -> String {
+    let n = position + 1;
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::MissingGreen { position, letter } => {
+                write!(f, "{} letter must be {}", ordinal(*position), letter.to_ascii_uppercase())
+            }
+            Violation::MissingLetter { letter, count: 1 } => {
+                write!(f, "Guess must contain {}", letter.to_ascii_uppercase())
+            }
+            Violation::MissingLetter { letter, count } => {
+                write!(f, "Guess must contain {} {}s", count, letter.to_ascii_uppercase())
+            }
+            Violation::GreyLetter { letter, count: 0 } => {
+                write!(f, "Guess must not contain {}", letter.to_ascii_uppercase())
+            }
+            Violation::GreyLetter { letter, count } => {
+                write!(f, "Guess must not contain more than {} {}", count, letter.to_ascii_uppercase())
+            }
+            Violation::RepeatedYellow { position, letter } => {
+                write!(f, "{} letter must not be {}", ordinal(*position), letter.to_ascii_uppercase())
+            }
+        }
+    }
+}
+
+// To accumulate every hint revealed by the previous guesses of a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraints {
+    greens: Vec<Option<char>>,
+    min_counts: BTreeMap<char, usize>,
+    max_counts: BTreeMap<char, usize>,
+    yellows: Vec<HashSet<char>>,
+}
+
+impl Constraints {
+    pub fn new(length: usize) -> Self {
+        Self {
+            greens: vec![None; length],
+            min_counts: BTreeMap::new(),
+            max_counts: BTreeMap::new(),
+            yellows: vec![HashSet::new(); length],
+        }
+    }
+
+    // To add the hints of one guess.
+    // A letter shown in grey next to greens or yellows of itself has an exact count.
+    pub fn update(&mut self, guess: &str, feedback: &Feedback) {
+        let mut found: BTreeMap<char, usize> = BTreeMap::new();
+        let mut grey: HashSet<char> = HashSet::new();
+        for (pos, (c, state)) in guess.chars().zip(feedback.tiles()).enumerate() {
+            match state {
+                TileState::Green => {
+                    self.greens[pos] = Some(c);
+                    *found.entry(c).or_insert(0) += 1;
+                }
+                TileState::Yellow => {
+                    self.yellows[pos].insert(c);
+                    *found.entry(c).or_insert(0) += 1;
+                }
+                TileState::Red => {
+                    grey.insert(c);
+                }
+            }
+        }
+        for (c, count) in &found {
+            let min = self.min_counts.entry(*c).or_insert(0);
+            if *count > *min {
+                *min = *count;
+            }
+        }
+        for c in grey {
+            self.max_counts.insert(c, found.get(&c).copied().unwrap_or(0));
+        }
+    }
+
+    // To check a guess against the rules of the given difficulty.
+    pub fn check(&self, guess: &str, difficulty: Difficulty) -> Result<(), Violation> {
+        if difficulty == Difficulty::Normal {
+            return Ok(());
+        }
+        let letters: Vec<char> = guess.chars().collect();
+        for (pos, green) in self.greens.iter().enumerate() {
+            if let Some(letter) = green {
+                if letters.get(pos) != Some(letter) {
+                    return Err(Violation::MissingGreen { position: pos, letter: *letter });
+                }
+            }
+        }
+        for (letter, count) in &self.min_counts {
+            if letters.iter().filter(|c| *c == letter).count() < *count {
+                return Err(Violation::MissingLetter { letter: *letter, count: *count });
+            }
+        }
+        if difficulty == Difficulty::UltraHard {
+            for (pos, c) in letters.iter().enumerate() {
+                if self.yellows.get(pos).is_some_and(|yellow| yellow.contains(c)) {
+                    return Err(Violation::RepeatedYellow { position: pos, letter: *c });
+                }
+            }
+            for (letter, count) in &self.max_counts {
+                if letters.iter().filter(|c| *c == letter).count() > *count {
+                    return Err(Violation::GreyLetter { letter: *letter, count: *count });
+                }
+            }
+        }
+        Ok(())
+    }
+}
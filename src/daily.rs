@@ -0,0 +1,73 @@
+// The date of the first daily puzzle, unless another epoch is configured.
+pub const DEFAULT_EPOCH: &str = "2021-06-19";
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// To count the days from 1970-01-01 to a date of the proleptic Gregorian calendar.
+pub fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// To turn a number of days from 1970-01-01 back into a date.
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+// To parse a date such as "2022-01-31", returning its number of days from 1970-01-01.
+pub fn parse_date(text: &str) -> Result<i64, String> {
+    let invalid = || format!("\"{}\" is not a date like YYYY-MM-DD", text.trim());
+    let parts: Vec<&str> = text.trim().split('-').collect();
+    let [year, month, day] = parts.as_slice() else { return Err(invalid()) };
+    let year: i64 = year.parse().map_err(|_| invalid())?;
+    let month: i64 = month.parse().map_err(|_| invalid())?;
+    let day: i64 = day.parse().map_err(|_| invalid())?;
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return Err(invalid());
+    }
+    Ok(days_from_civil(year, month, day))
+}
+
+pub fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// The date in UTC of a time in seconds since the Unix epoch, as a number of days from 1970-01-01.
+pub fn day_of(time: u64) -> i64 {
+    (time / 86400) as i64
+}
+
+// The number of the puzzle of a date: 0 on the epoch, negative before it.
+pub fn puzzle_number(date: i64, epoch: i64) -> i64 {
+    date - epoch
+}
+
+// To map a puzzle number to a position in a list of "len" answers, going round the list in both directions.
+pub fn puzzle_index(number: i64, len: usize) -> usize {
+    number.rem_euclid(len as i64) as usize
+}
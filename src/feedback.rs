@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::fmt;
+
+// The 26 letters shown in the keyboard summary.
+pub const CHAR_LIST: &[char; 26] = &['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'];
+
+// To describe the colour of a single tile.
+// The order "Red < Yellow < Green" is the order in which a letter's state may be upgraded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TileState {
+    Red,
+    Yellow,
+    Green,
+}
+
+impl TileState {
+    pub fn to_char(self) -> char {
+        match self {
+            TileState::Green => 'G',
+            TileState::Yellow => 'Y',
+            TileState::Red => 'R',
+        }
+    }
+
+    fn digit(self) -> u32 {
+        match self {
+            TileState::Red => 0,
+            TileState::Yellow => 1,
+            TileState::Green => 2,
+        }
+    }
+
+    pub fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'G' => Some(TileState::Green),
+            'Y' => Some(TileState::Yellow),
+            'R' => Some(TileState::Red),
+            _ => None,
+        }
+    }
+}
+
+// To record the colours of all tiles of one guess.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Feedback {
+    tiles: Vec<TileState>,
+}
+
+impl Feedback {
+    pub fn new(tiles: Vec<TileState>) -> Self {
+        Self { tiles }
+    }
+
+    // To parse a pattern such as "RRYGR".
+    pub fn parse(pattern: &str) -> Option<Self> {
+        let tiles: Option<Vec<TileState>> = pattern.trim().chars().map(TileState::from_char).collect();
+        tiles.map(Self::new)
+    }
+
+    // To decode a pattern from its base-3 number, the first tile being the most significant digit.
+    pub fn from_code(code: u32, length: usize) -> Self {
+        let mut tiles = vec![TileState::Red; length];
+        let mut rest = code;
+        for tile in tiles.iter_mut().rev() {
+            *tile = match rest % 3 {
+                2 => TileState::Green,
+                1 => TileState::Yellow,
+                _ => TileState::Red,
+            };
+            rest /= 3;
+        }
+        Self::new(tiles)
+    }
+
+    // To encode the pattern as a base-3 number, which is cheap to compare and count.
+    pub fn code(&self) -> u32 {
+        self.tiles.iter().fold(0, |code, tile| code * 3 + tile.digit())
+    }
+
+    pub fn tiles(&self) -> &[TileState] {
+        &self.tiles
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    // A guess is solved when every tile is green.
+    pub fn is_solved(&self) -> bool {
+        self.tiles.iter().all(|t| *t == TileState::Green)
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for tile in &self.tiles {
+            write!(f, "{}", tile.to_char())?;
+        }
+        Ok(())
+    }
+}
+
+// To score a guess against the answer.
+// Both words must have the same length.
+pub fn evaluate(answer: &str, guess: &str) -> Feedback {
+    Feedback::from_code(evaluate_code(answer, guess), guess.len())
+}
+
+// To score a guess against the answer as a base-3 number, see "Feedback::code".
+// Greens are matched first, then every other letter takes a yellow
+// only while unmatched copies of it remain in the answer.
+pub fn evaluate_code(answer: &str, guess: &str) -> u32 {
+    evaluate_union_code(&[answer], guess)
+}
+
+// In "Xordle Mode", to score a guess against several answers at once.
+// A tile is green when it matches any of the answers,
+// and yellow while unmatched copies of its letter remain in any of them.
+pub fn evaluate_union(answers: &[&str], guess: &str) -> Feedback {
+    Feedback::from_code(evaluate_union_code(answers, guess), guess.len())
+}
+
+// In "Xordle Mode", to score a guess against several answers as a base-3 number, see "evaluate_union".
+pub fn evaluate_union_code(answers: &[&str], guess: &str) -> u32 {
+    let guess = guess.as_bytes();
+    let mut unmatched = [0u8; 256];
+    for answer in answers {
+        let answer = answer.as_bytes();
+        assert_eq!(answer.len(), guess.len(), "the guess and the answer must have the same length");
+        for (a, g) in answer.iter().zip(guess) {
+            if a != g {
+                unmatched[*a as usize] += 1;
+            }
+        }
+    }
+    let mut code = 0;
+    for (i, g) in guess.iter().enumerate() {
+        let state = if answers.iter().any(|answer| answer.as_bytes()[i] == *g) {
+            TileState::Green
+        } else if unmatched[*g as usize] > 0 {
+            unmatched[*g as usize] -= 1;
+            TileState::Yellow
+        } else {
+            TileState::Red
+        };
+        code = code * 3 + state.digit();
+    }
+    code
+}
+
+// To accumulate the best known state of every letter over a game.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyboardState {
+    letters: HashMap<char, TileState>,
+}
+
+impl KeyboardState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // A letter is only ever upgraded, e.g. a green letter never turns back to yellow.
+    pub fn update(&mut self, guess: &str, feedback: &Feedback) {
+        for (c, state) in guess.chars().zip(feedback.tiles()) {
+            let current = self.letters.entry(c).or_insert(*state);
+            if *state > *current {
+                *current = *state;
+            }
+        }
+    }
+
+    // To combine the states of several keyboards, keeping the best state of each letter.
+    pub fn merge(&mut self, other: &KeyboardState) {
+        for (c, state) in &other.letters {
+            let current = self.letters.entry(*c).or_insert(*state);
+            if *state > *current {
+                *current = *state;
+            }
+        }
+    }
+
+    // Returns "None" for letters that have not been guessed yet.
+    pub fn get(&self, letter: char) -> Option<TileState> {
+        self.letters.get(&letter).copied()
+    }
+}
+
+// Displayed as 26 characters, using 'X' for unknown letters.
+impl fmt::Display for KeyboardState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in CHAR_LIST {
+            write!(f, "{}", self.get(*c).map_or('X', TileState::to_char))?;
+        }
+        Ok(())
+    }
+}
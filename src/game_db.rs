@@ -0,0 +1,245 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+
+// A history of games in a SQLite database, as an alternative to a JSON state file growing with every game.
+// Every game is kept as its JSON, next to the columns the statistics are queried by:
+// games are appended without rewriting the others, and statistics per player or per date use an index.
+pub struct GameDb {
+    conn: Connection,
+}
+
+// The columns of a game queried by the statistics, besides its JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRow {
+    pub player: Option<String>,
+    // The day the game was played, such as "2022-01-31".
+    pub date: Option<String>,
+    // "won", "lost" or "survived".
+    pub outcome: String,
+    // How the answer was chosen.
+    pub source: String,
+    pub tries: usize,
+    pub data: String,
+}
+
+// A run of "Survival Mode", as kept in the high scores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SurvivalRow {
+    pub rule: String,
+    pub score: u64,
+    pub max_tries: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grouping {
+    Player,
+    Date,
+}
+
+impl Grouping {
+    fn column(self) -> &'static str {
+        match self {
+            Grouping::Player => "player",
+            Grouping::Date => "date",
+        }
+    }
+}
+
+// The statistics of the games of a player or a date. Only won games count in "tries_won".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupStats {
+    pub key: Option<String>,
+    pub games: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub tries_won: u64,
+}
+
+impl GroupStats {
+    pub fn average_tries(&self) -> f64 {
+        if self.wins == 0 {
+            0.0
+        } else {
+            self.tries_won as f64 / self.wins as f64
+        }
+    }
+}
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS meta (key TEXT PRIMARY KEY, value INTEGER NOT NULL);
+    CREATE TABLE IF NOT EXISTS games (
+        id INTEGER PRIMARY KEY,
+        player TEXT,
+        date TEXT,
+        outcome TEXT NOT NULL,
+        source TEXT NOT NULL,
+        tries INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS games_by_player ON games (player, outcome);
+    CREATE INDEX IF NOT EXISTS games_by_player_source ON games (player, source);
+    CREATE INDEX IF NOT EXISTS games_by_date ON games (date, outcome);
+    CREATE TABLE IF NOT EXISTS survival (rule TEXT NOT NULL, score INTEGER NOT NULL, max_tries INTEGER NOT NULL);
+";
+
+fn error(e: rusqlite::Error) -> String {
+    e.to_string()
+}
+
+impl GameDb {
+    // To open a database, creating it and its tables if needed.
+    pub fn open(path: &str) -> Result<Self, String> {
+        let conn = Connection::open(path).map_err(|e| format!("{}: {}", path, e))?;
+        // Wait for other processes writing at the same time instead of failing.
+        conn.busy_timeout(std::time::Duration::from_secs(10)).map_err(error)?;
+        conn.execute_batch(SCHEMA).map_err(|e| format!("{}: {}", path, e))?;
+        Ok(Self { conn })
+    }
+
+    pub fn total_rounds(&self) -> Result<u64, String> {
+        let value: Option<i64> = self.conn
+            .query_row("SELECT value FROM meta WHERE key = 'total_rounds'", [], |row| row.get(0))
+            .optional()
+            .map_err(error)?;
+        Ok(value.unwrap_or(0) as u64)
+    }
+
+    // To append games in a single transaction, counting "rounds" more rounds.
+    pub fn append(&mut self, games: &[GameRow], rounds: u64) -> Result<(), String> {
+        self.insert(games, rounds, false).map(|_| ())
+    }
+
+    // To append only the games not in the database yet, so that a state file can be imported again.
+    // A game is already there when as many games with the same JSON are, and then only the games appended count as rounds.
+    // Returns the number of games appended.
+    pub fn import(&mut self, games: &[GameRow], rounds: u64) -> Result<usize, String> {
+        self.insert(games, rounds, true)
+    }
+
+    fn insert(&mut self, games: &[GameRow], rounds: u64, skips_known: bool) -> Result<usize, String> {
+        let transaction = self.conn.transaction().map_err(error)?;
+        let mut appended = 0;
+        {
+            let mut insert = transaction
+                .prepare("INSERT INTO games (player, date, outcome, source, tries, data) VALUES (?1, ?2, ?3, ?4, ?5, ?6)")
+                .map_err(error)?;
+            let mut count = transaction.prepare("SELECT COUNT(*) FROM games WHERE data = ?1").map_err(error)?;
+            // The games with each JSON in the database before appending, less the ones skipped so far.
+            let mut known: HashMap<&str, i64> = HashMap::new();
+            for game in games {
+                if skips_known {
+                    let left = match known.get_mut(game.data.as_str()) {
+                        Some(left) => left,
+                        None => {
+                            let left = count.query_row([&game.data], |row| row.get(0)).map_err(error)?;
+                            known.entry(game.data.as_str()).or_insert(left)
+                        }
+                    };
+                    if *left > 0 {
+                        *left -= 1;
+                        continue;
+                    }
+                }
+                insert
+                    .execute(params![game.player, game.date, game.outcome, game.source, game.tries as i64, game.data])
+                    .map_err(error)?;
+                appended += 1;
+            }
+            // Once a game is skipped, the rounds counted by the state file have been counted before.
+            let rounds = if appended < games.len() { appended as u64 } else { rounds };
+            transaction
+                .execute(
+                    "INSERT INTO meta (key, value) VALUES ('total_rounds', ?1)
+                     ON CONFLICT (key) DO UPDATE SET value = value + ?1",
+                    params![rounds as i64],
+                )
+                .map_err(error)?;
+        }
+        transaction.commit().map_err(error)?;
+        Ok(appended)
+    }
+
+    // The JSON of the games of a player and of a source, or of all of them if "None", in the order they were appended.
+    pub fn games_of(&self, player: Option<&str>, source: Option<&str>) -> Result<Vec<String>, String> {
+        let mut filters = Vec::new();
+        let mut values = Vec::new();
+        if let Some(player) = player {
+            values.push(player);
+            filters.push(format!("player = ?{}", values.len()));
+        }
+        if let Some(source) = source {
+            values.push(source);
+            filters.push(format!("source = ?{}", values.len()));
+        }
+        let filter = if filters.is_empty() { String::new() } else { format!("WHERE {}", filters.join(" AND ")) };
+        let mut select = self.conn.prepare(&format!("SELECT data FROM games {} ORDER BY id", filter)).map_err(error)?;
+        let rows = select.query_map(rusqlite::params_from_iter(values), |row| row.get(0)).map_err(error)?;
+        rows.collect::<Result<_, _>>().map_err(error)
+    }
+
+    // Whether the daily puzzle of a date has been played, by the given player if any.
+    pub fn has_daily(&self, date: &str, player: Option<&str>) -> Result<bool, String> {
+        let mut select = self.conn
+            .prepare(
+                "SELECT 1 FROM games WHERE date = ?1 AND json_extract(data, '$.date') = ?1
+                 AND (?2 IS NULL OR player = ?2)",
+            )
+            .map_err(error)?;
+        select.exists(params![date, player]).map_err(error)
+    }
+
+    pub fn survival(&self) -> Result<Vec<SurvivalRow>, String> {
+        let mut select = self.conn.prepare("SELECT rule, score, max_tries FROM survival ORDER BY rowid").map_err(error)?;
+        let rows = select
+            .query_map([], |row| {
+                Ok(SurvivalRow {
+                    rule: row.get(0)?,
+                    score: row.get::<_, i64>(1)? as u64,
+                    max_tries: row.get::<_, i64>(2)? as usize,
+                })
+            })
+            .map_err(error)?;
+        rows.collect::<Result<_, _>>().map_err(error)
+    }
+
+    // To replace the high scores of "Survival Mode".
+    pub fn set_survival(&mut self, runs: &[SurvivalRow]) -> Result<(), String> {
+        let transaction = self.conn.transaction().map_err(error)?;
+        transaction.execute("DELETE FROM survival", []).map_err(error)?;
+        for run in runs {
+            transaction
+                .execute(
+                    "INSERT INTO survival (rule, score, max_tries) VALUES (?1, ?2, ?3)",
+                    params![run.rule, run.score as i64, run.max_tries as i64],
+                )
+                .map_err(error)?;
+        }
+        transaction.commit().map_err(error)
+    }
+
+    // The statistics of every player or date, sorted by it, or only of the given one.
+    pub fn stats(&self, grouping: Grouping, key: Option<&str>) -> Result<Vec<GroupStats>, String> {
+        let column = grouping.column();
+        let filter = if key.is_some() { format!("WHERE {} = ?1", column) } else { String::new() };
+        let query = format!(
+            "SELECT {column}, COUNT(*), SUM(outcome = 'won'), SUM(outcome = 'lost'), SUM(CASE WHEN outcome = 'won' THEN tries ELSE 0 END)
+             FROM games {filter} GROUP BY {column} ORDER BY {column}"
+        );
+        let mut select = self.conn.prepare(&query).map_err(error)?;
+        let to_stats = |row: &rusqlite::Row| {
+            Ok(GroupStats {
+                key: row.get(0)?,
+                games: row.get::<_, i64>(1)? as u64,
+                wins: row.get::<_, i64>(2)? as u64,
+                losses: row.get::<_, i64>(3)? as u64,
+                tries_won: row.get::<_, i64>(4)? as u64,
+            })
+        };
+        let rows = match key {
+            Some(key) => select.query_map([key], to_stats),
+            None => select.query_map([], to_stats),
+        }
+        .map_err(error)?;
+        rows.collect::<Result<_, _>>().map_err(error)
+    }
+}
@@ -0,0 +1,17 @@
+pub mod bench;
+pub mod builtin_words;
+pub mod constraints;
+pub mod daily;
+pub mod feedback;
+#[cfg(feature = "sqlite")]
+pub mod game_db;
+pub mod puzzle_code;
+pub mod reverse;
+pub mod round;
+pub mod solver;
+pub mod state_file;
+pub mod tree;
+pub mod xordle;
+
+pub use constraints::{Constraints, Difficulty, Violation};
+pub use feedback::{evaluate, evaluate_code, evaluate_union, Feedback, KeyboardState, TileState, CHAR_LIST};
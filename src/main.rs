@@ -1,28 +1,97 @@
-pub mod builtin_words;
 use clap::App;
-use console;
 use core::panic;
 use std::io::{self, Write};
 use colored::*;
-use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rand::prelude::SliceRandom;
 use std::collections::HashMap;
-use std::collections::BTreeSet;
-use std::fs::{File, OpenOptions};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter};
 use serde::{Serialize, Deserialize};
+use wordle::builtin_words;
+use wordle::solver::{self, RankedGuess};
+use wordle::tree::{DecisionTree, TreeExport};
+use wordle::xordle;
+use wordle::reverse::{self, Puzzle};
+use wordle::puzzle_code::PuzzleCode;
+use wordle::daily;
+use wordle::state_file;
+#[cfg(feature = "sqlite")]
+use wordle::game_db::{GameDb, GameRow, Grouping, SurvivalRow};
+use wordle::round::{GuessError, Round, UNLIMITED_TRIES};
+use wordle::bench::{self, FirstStrategy, RandomStrategy, Strategy, TreeStrategy};
+use wordle::{Difficulty, Feedback, KeyboardState, TileState, CHAR_LIST};
 
 // To definite relevant constants.
-const WORD_LENGTH: usize = 5;
+const DEFAULT_LENGTH: usize = 5;
+const MIN_LENGTH: usize = 3;
+const MAX_LENGTH: usize = 12;
 const MAX_TRIES: usize = 6;
 const DEFAULT_SEED: u64 = 20031007;
-const CHAR_LIST: &[char; 26] = &['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'];
+const TOP_WORDS: usize = 5;
+const TOP_GUESSES: usize = 10;
+const MAX_BOARDS: usize = 32;
+const HIGH_SCORES: usize = 10;
+#[cfg(not(feature = "sqlite"))]
+const NO_SQLITE: &str = "This build has no SQLite support, rebuild it with --features sqlite!";
+const REVERSE_ROWS: usize = 5;
+// The version of the state file format written by default.
+const STATE_VERSION: u32 = 1;
+// The ways the answer of a recorded game can be chosen.
+const SOURCES: &[&str] = &["word", "manual", "random", "daily", "code", "absurdle", "reverse"];
 
 // To sanitize words to simpler forms.
 fn sanitize(word: &str) -> String {
     word.trim().to_lowercase().chars().filter(|c| c.is_ascii_alphabetic()).collect()
 }
 
+// To parse the answers given by "-w", separated by commas when there are several boards,
+// or the guesses given by "--prefill".
+fn parse_words(word: &str) -> Vec<String> {
+    word.split(',').map(sanitize).collect()
+}
+
+// To parse the rows given by "--rows", e.g. "RRYGR,GYRRG".
+fn parse_rows(rows: &str) -> Option<Vec<Feedback>> {
+    rows.split(',').map(Feedback::parse).collect()
+}
+
+// To parse the number of tries, where "unlimited" or "0" means no limit.
+fn parse_max_tries(value: &str) -> usize {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("unlimited") {
+        UNLIMITED_TRIES
+    } else {
+        value.parse().unwrap()
+    }
+}
+
+// The current time in seconds since the Unix epoch, or the time given by the environment variable "WORDLE_NOW".
+fn now() -> u64 {
+    if let Some(time) = std::env::var("WORDLE_NOW").ok().and_then(|time| time.trim().parse().ok()) {
+        return time;
+    }
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs())
+}
+
+// Whether a name can be given to a player: a single word, other than "-" standing for the games without a player.
+fn is_player_name(name: &str) -> bool {
+    !name.is_empty() && name != "-" && !name.contains(char::is_whitespace)
+}
+
+// To colour a letter according to its state.
+fn paint(c: char, state: Option<TileState>) -> ColoredString {
+    let letter = c.to_uppercase().to_string();
+    match state {
+        Some(TileState::Green) => letter.bright_green(),
+        Some(TileState::Yellow) => letter.bright_yellow(),
+        Some(TileState::Red) => letter.red(),
+        None => letter.normal(),
+    }
+}
 
 // To load, filter and sort the word list.
 // Use the data structure "BTreeSet" to realize automatic sorting.
@@ -35,7 +104,7 @@ fn load_data(path: &str, dic: &mut Vec<String>) -> BTreeSet<String> {
     let file = File::open(filename).unwrap();
     let fin = BufReader::new(file);
     for line in fin.lines() {
-        let word = (&line.unwrap()).clone();
+        let word = line.unwrap();
         word_set.insert(word);
     }
     for i in &word_set {
@@ -46,24 +115,71 @@ fn load_data(path: &str, dic: &mut Vec<String>) -> BTreeSet<String> {
 
 // To record the state of each Wordle Game.
 struct WordleGame {
-    word: String,
-    guesses: Vec<String>,
-    conditions: HashMap<char, char>,
-    green_pos: [bool; 5],
-    yellow_num: HashMap<char, u64>,
+    length: usize,
+    max_tries: usize,
+    difficulty: Difficulty,
+    // One round per board, all of them receiving the same guesses until they are over.
+    boards: Vec<Round>,
+    // In "Fibble Mode", the generator seeding the lies of each game.
+    liar: Option<StdRng>,
+    // In "Xordle Mode", the two answers of a game are played on a single board.
+    xordle: bool,
+    // In "Anti Mode", the answer is to be avoided, and the number of guesses survived in each game.
+    anti: bool,
+    anti_scores: Vec<u64>,
+    // The number of guesses of the game replayed by "--prefill" before the player took over.
+    prefilled: usize,
+    // In "Daily Mode", the date of the puzzle.
+    date: Option<String>,
+    // How the answers of this run are chosen, and the only source counted in the statistics if given by "--source".
+    source: &'static str,
+    stats_source: Option<String>,
+    // What identifies a game besides its answer: the player, the word lists, and the seed and day of a random answer.
+    player: Option<String>,
+    word_list: String,
+    seed: Option<u64>,
+    day: Option<u64>,
+    // In "Timed Mode", the time limits of a game and of a guess, when the game and the latest guess started,
+    // and the time taken by each guess in milliseconds.
+    time_limit: Option<Duration>,
+    guess_time_limit: Option<Duration>,
+    started: Instant,
+    guess_started: Instant,
+    guess_times: Vec<u64>,
+    timed_out: bool,
+    // The total times of the timed games won, in milliseconds.
+    solve_times: Vec<u64>,
     win: u64,
     lose: u64,
     tries: u64,
+    tries_win: u64,
+    hints: u64,
     used_words: HashMap<String, u64>,
 }
 
 // Use struct "Games" and "Game" to parse json files.
 #[derive(Debug, Serialize, Deserialize)]
 struct Games {
+    // Files without a version are of version 0, see "migrate_state".
+    #[serde(default = "default_version")]
+    version: u32,
     #[serde(default = "default_total_rounds")]
     total_rounds: u64,
     #[serde(default = "default_games")]
     games: Vec<Game>,
+    // In "Survival Mode", the best runs of each rule and number of tries.
+    #[serde(default = "default_survival_scores", skip_serializing_if = "Vec::is_empty")]
+    survival: Vec<SurvivalScore>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SurvivalScore {
+    #[serde(default = "default_survival")]
+    rule: String,
+    #[serde(default = "default_score")]
+    score: u64,
+    #[serde(default = "default_max_tries")]
+    max_tries: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -71,11 +187,60 @@ struct Game {
     #[serde(default = "default_answer")]
     answer: String,
     #[serde(default = "default_guesses")]
-    guesses: Vec<String>
+    guesses: Vec<String>,
+    #[serde(default = "default_max_tries")]
+    max_tries: usize,
+    #[serde(default = "default_hints")]
+    hints: u64,
+    // With more than one board, the answers of all boards, "answer" being the first of them.
+    #[serde(default = "default_answers", skip_serializing_if = "Vec::is_empty")]
+    answers: Vec<String>,
+    // In "Fibble Mode", the position of the lying tile of every row but a winning one.
+    #[serde(default = "default_lies", skip_serializing_if = "Vec::is_empty")]
+    lies: Vec<usize>,
+    // In "Timed Mode", the time taken by each guess and by the whole game, in milliseconds.
+    #[serde(default = "default_guess_times", skip_serializing_if = "Vec::is_empty")]
+    guess_times: Vec<u64>,
+    #[serde(default = "default_time", skip_serializing_if = "Option::is_none")]
+    time: Option<u64>,
+    // In "Anti Mode", the number of guesses survived before hitting the answer.
+    #[serde(default = "default_survived", skip_serializing_if = "Option::is_none")]
+    survived: Option<u64>,
+    // With "--prefill", the number of guesses replayed at the start of "guesses" instead of being entered.
+    #[serde(default = "default_prefilled", skip_serializing_if = "Option::is_none")]
+    prefilled: Option<usize>,
+    // In "Daily Mode", the date of the puzzle, which cannot be played again.
+    #[serde(default = "default_date", skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    // In "Xordle Mode", "answers" are the two answers of a single board.
+    #[serde(default = "default_xordle", skip_serializing_if = "std::ops::Not::not")]
+    xordle: bool,
+    // In "Reverse Mode", the rows to give against the answer, "guesses" being the words entered.
+    #[serde(default = "default_game_rows", skip_serializing_if = "Vec::is_empty")]
+    rows: Vec<String>,
+    // How the answer was chosen, one of "SOURCES". Games saved before sources were recorded are all random ones.
+    #[serde(default = "default_source")]
+    source: String,
+    // Since version 1: when the game ended in seconds since the Unix epoch, who played it,
+    // its difficulty and word lists, the seed and day of a random answer, and "won", "lost" or "survived".
+    #[serde(default = "default_timestamp", skip_serializing_if = "Option::is_none")]
+    timestamp: Option<u64>,
+    #[serde(default = "default_player", skip_serializing_if = "Option::is_none")]
+    player: Option<String>,
+    #[serde(default = "default_game_difficulty", skip_serializing_if = "Option::is_none")]
+    difficulty: Option<Difficulty>,
+    #[serde(default = "default_word_list", skip_serializing_if = "Option::is_none")]
+    word_list: Option<String>,
+    #[serde(default = "default_game_seed", skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    #[serde(default = "default_game_day", skip_serializing_if = "Option::is_none")]
+    day: Option<u64>,
+    #[serde(default = "default_outcome", skip_serializing_if = "String::is_empty")]
+    outcome: String,
 }
 
 // Use struct "Configuration" to record default configurations.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Configuration {
     #[serde(default = "default_word")]
     word: String,
@@ -83,6 +248,20 @@ struct Configuration {
     random: bool,
     #[serde(default = "default_difficult")]
     difficult: bool,
+    #[serde(default = "default_ultra_hard")]
+    ultra_hard: bool,
+    #[serde(default = "default_absurdle")]
+    absurdle: bool,
+    #[serde(default = "default_fibble")]
+    fibble: bool,
+    #[serde(default = "default_xordle")]
+    xordle: bool,
+    #[serde(default = "default_anti")]
+    anti: bool,
+    #[serde(default = "default_reverse")]
+    reverse: bool,
+    #[serde(default = "default_rows")]
+    rows: String,
     #[serde(default = "default_stats")]
     stats: bool,
     #[serde(default = "default_day")]
@@ -95,536 +274,695 @@ struct Configuration {
     acceptable_set: String,
     #[serde(default = "default_state")]
     state: String,
+    #[serde(default = "default_length")]
+    length: usize,
+    #[serde(default = "default_max_tries")]
+    max_tries: usize,
+    #[serde(default = "default_boards")]
+    boards: usize,
+    #[serde(default = "default_survival")]
+    survival: String,
+    #[serde(default = "default_timed")]
+    timed: u64,
+    #[serde(default = "default_guess_time")]
+    guess_time: u64,
+    #[serde(default = "default_prefill")]
+    prefill: String,
+    #[serde(default = "default_daily")]
+    daily: bool,
+    #[serde(default = "default_daily_date")]
+    date: String,
+    #[serde(default = "default_epoch")]
+    epoch: String,
+    #[serde(default = "default_stats_source")]
+    stats_source: String,
+    #[serde(default = "default_state_format_version")]
+    state_format_version: u32,
+    #[serde(default = "default_db")]
+    db: String,
+    #[serde(default = "default_player_name")]
+    player: String,
 }
 
+fn default_version() -> u32 { 0 }
+fn default_state_format_version() -> u32 { STATE_VERSION }
 fn default_total_rounds() -> u64 { 0 }
 fn default_games() -> Vec<Game> { let _vec: Vec<Game> = Vec::new(); _vec }
+fn default_survival_scores() -> Vec<SurvivalScore> { let _vec: Vec<SurvivalScore> = Vec::new(); _vec }
+fn default_score() -> u64 { 0 }
 fn default_answer() -> String { let _str: String = String::new(); _str }
 fn default_guesses() -> Vec<String> { let _vec:Vec<String> = Vec::new(); _vec }
 fn default_word() -> String { let _str: String = String::new(); _str }
 fn default_random() -> bool { false }
 fn default_difficult() -> bool { false }
+fn default_ultra_hard() -> bool { false }
+fn default_absurdle() -> bool { false }
+fn default_fibble() -> bool { false }
+fn default_xordle() -> bool { false }
+fn default_anti() -> bool { false }
+fn default_reverse() -> bool { false }
+fn default_rows() -> String { let _str: String = String::new(); _str }
 fn default_stats() -> bool { false }
 fn default_day() -> u64 { 1 }
 fn default_seed() -> u64 { DEFAULT_SEED }
 fn default_final_set() -> String { let _str: String = String::new(); _str }
 fn default_acceptable_set() -> String { let _str: String = String::new(); _str }
 fn default_state() -> String { let _str: String = String::new(); _str }
+fn default_length() -> usize { DEFAULT_LENGTH }
+fn default_max_tries() -> usize { MAX_TRIES }
+fn default_hints() -> u64 { 0 }
+fn default_answers() -> Vec<String> { let _vec: Vec<String> = Vec::new(); _vec }
+fn default_boards() -> usize { 1 }
+fn default_survival() -> String { let _str: String = String::new(); _str }
+fn default_timed() -> u64 { 0 }
+fn default_guess_time() -> u64 { 0 }
+fn default_lies() -> Vec<usize> { let _vec: Vec<usize> = Vec::new(); _vec }
+fn default_guess_times() -> Vec<u64> { let _vec: Vec<u64> = Vec::new(); _vec }
+fn default_time() -> Option<u64> { None }
+fn default_survived() -> Option<u64> { None }
+fn default_prefilled() -> Option<usize> { None }
+fn default_date() -> Option<String> { None }
+fn default_game_rows() -> Vec<String> { Vec::new() }
+fn default_source() -> String { "random".to_string() }
+fn default_timestamp() -> Option<u64> { None }
+fn default_player() -> Option<String> { None }
+fn default_game_difficulty() -> Option<Difficulty> { None }
+fn default_word_list() -> Option<String> { None }
+fn default_game_seed() -> Option<u64> { None }
+fn default_game_day() -> Option<u64> { None }
+fn default_outcome() -> String { let _str: String = String::new(); _str }
+fn default_stats_source() -> String { let _str: String = String::new(); _str }
+fn default_daily() -> bool { false }
+fn default_daily_date() -> String { let _str: String = String::new(); _str }
+fn default_epoch() -> String { daily::DEFAULT_EPOCH.to_string() }
+fn default_prefill() -> String { let _str: String = String::new(); _str }
+fn default_db() -> String { let _str: String = String::new(); _str }
+fn default_player_name() -> String { let _str: String = String::new(); _str }
 
 impl Game {
     fn new() -> Self {
-        Self { 
-            answer: default_answer(), 
+        Self {
+            answer: default_answer(),
             guesses: default_guesses(),
+            max_tries: default_max_tries(),
+            hints: default_hints(),
+            answers: default_answers(),
+            lies: default_lies(),
+            guess_times: default_guess_times(),
+            time: default_time(),
+            survived: default_survived(),
+            prefilled: default_prefilled(),
+            date: default_date(),
+            xordle: default_xordle(),
+            rows: default_game_rows(),
+            source: default_source(),
+            timestamp: default_timestamp(),
+            player: default_player(),
+            difficulty: default_game_difficulty(),
+            word_list: default_word_list(),
+            seed: default_game_seed(),
+            day: default_game_day(),
+            outcome: default_outcome(),
+        }
+    }
+
+    // The outcome as recorded since version 1, derived from the other fields for older games.
+    fn derive_outcome(&self) -> &'static str {
+        if self.survived.is_some() {
+            "survived"
+        } else if self.is_win() {
+            "won"
+        } else {
+            "lost"
+        }
+    }
+
+    // A game is won when every answer has been guessed, or in "Reverse Mode" when every row has been given.
+    fn is_win(&self) -> bool {
+        if let Some(puzzle) = self.replay_reverse() {
+            puzzle.is_won()
+        } else if self.answers.is_empty() {
+            self.guesses.contains(&self.answer)
+        } else {
+            self.answers.iter().all(|answer| self.guesses.contains(answer))
+        }
+    }
+
+    // In "Reverse Mode", the puzzle after entering the words again. Returns "None" for other games.
+    fn replay_reverse(&self) -> Option<Puzzle> {
+        if self.rows.is_empty() {
+            return None;
+        }
+        let rows = self.rows.iter().map(|row| Feedback::parse(row)).collect::<Option<Vec<Feedback>>>()?;
+        let mut puzzle = Puzzle::new(&self.answer.to_lowercase(), rows, UNLIMITED_TRIES);
+        for guess in &self.guesses {
+            puzzle.play(&guess.to_lowercase());
         }
+        Some(puzzle)
     }
 }
 
 impl Configuration {
+    // The number of answers drawn for each game: two in "Xordle Mode", or one for each board.
+    fn answers_per_game(&self) -> usize {
+        if self.xordle { 2 } else { self.boards }
+    }
+
+    // Whether the games are recorded, in the state file given by "-S" or the database given by "--db".
+    fn keeps_history(&self) -> bool {
+        !self.state.is_empty() || !self.db.is_empty()
+    }
+
     fn new() -> Self {
-        Self { 
-            word: default_word(), 
-            random: default_random(), 
-            difficult: default_difficult(), 
-            stats: default_stats(), 
-            day: default_day(), 
-            seed: default_seed(), 
-            final_set: default_final_set(), 
-            acceptable_set: default_acceptable_set(), 
-            state: default_state(),
-        }
-    }    
-    fn clone(&self) -> Self {
         Self {
-            word: self.word.clone(), 
-            random: self.random.clone(), 
-            difficult: self.difficult.clone(), 
-            stats: self.stats.clone(), 
-            day: self.day.clone(), 
-            seed: self.seed.clone(), 
-            final_set: self.final_set.clone(), 
-            acceptable_set: self.acceptable_set.clone(), 
-            state: self.state.clone(),
+            word: default_word(),
+            random: default_random(),
+            difficult: default_difficult(),
+            ultra_hard: default_ultra_hard(),
+            absurdle: default_absurdle(),
+            fibble: default_fibble(),
+            xordle: default_xordle(),
+            anti: default_anti(),
+            reverse: default_reverse(),
+            rows: default_rows(),
+            stats: default_stats(),
+            day: default_day(),
+            seed: default_seed(),
+            final_set: default_final_set(),
+            acceptable_set: default_acceptable_set(),
+            state: default_state(),
+            length: default_length(),
+            max_tries: default_max_tries(),
+            boards: default_boards(),
+            survival: default_survival(),
+            timed: default_timed(),
+            guess_time: default_guess_time(),
+            prefill: default_prefill(),
+            daily: default_daily(),
+            date: default_daily_date(),
+            epoch: default_epoch(),
+            stats_source: default_stats_source(),
+            state_format_version: default_state_format_version(),
+            db: default_db(),
+            player: default_player_name(),
         }
     }
 }
 
 impl WordleGame {
     fn new() -> Self {
-        let mut cond: HashMap<char, char> = HashMap::new();
-        let mut y_num: HashMap<char, u64> = HashMap::new();
-        for c in CHAR_LIST { 
-            cond.insert(*c, 'X');
-            y_num.insert(*c, 0); 
-        }
         Self {
-            word: String::new(),
-            guesses: Vec::new(),
-            conditions: cond,
-            green_pos: [false, false, false, false, false],
-            yellow_num: y_num,
+            length: DEFAULT_LENGTH,
+            max_tries: MAX_TRIES,
+            difficulty: Difficulty::Normal,
+            boards: Vec::new(),
+            liar: None,
+            xordle: false,
+            anti: false,
+            anti_scores: Vec::new(),
+            prefilled: 0,
+            date: None,
+            source: "manual",
+            stats_source: None,
+            player: None,
+            word_list: "builtin".to_string(),
+            seed: None,
+            day: None,
+            time_limit: None,
+            guess_time_limit: None,
+            started: Instant::now(),
+            guess_started: Instant::now(),
+            guess_times: Vec::new(),
+            timed_out: false,
+            solve_times: Vec::new(),
             win: 0,
             lose: 0,
             tries: 0,
+            tries_win: 0,
+            hints: 0,
             used_words: HashMap::new(),
         }
     }
 
-    fn update(&mut self) {
-        self.guesses = Vec::new();
-        self.conditions = HashMap::new();
-        self.green_pos = [false, false, false, false, false];
-        self.yellow_num = HashMap::new();
+    // To start a new game with one board for each given answer.
+    // In "Xordle Mode", the two answers are played on a single board.
+    fn start(&mut self, words: &[String]) {
+        if self.xordle {
+            self.boards = vec![Round::xordle(&words[0], &words[1], self.difficulty, self.max_tries)];
+            self.reset();
+            return;
+        }
+        if self.anti {
+            self.boards = vec![Round::anti(&words[0])];
+            self.reset();
+            return;
+        }
+        self.boards = words.iter().map(|word| match &mut self.liar {
+            Some(rng) => Round::fibble(word, self.difficulty, self.max_tries, rng.gen()),
+            None => Round::new(word, self.difficulty, self.max_tries),
+        }).collect();
+        self.reset();
     }
 
-    // In "Interactive Mode", display the result of each guess.
-    fn display_guesses(&mut self) {
-        self.guesses.iter().enumerate().for_each(|(guess_number, guess)| {
-            print!("{}: ", guess_number + 1);
-            let mut green_word_update: [bool; 5] = [false, false, false, false, false];
-            let mut yellow_word_update: HashMap<char, u64> = HashMap::new();
-            let mut yellow_word_nums: HashMap<char, u64> = HashMap::new();
-            for c in CHAR_LIST {
-                yellow_word_nums.insert(*c, 0);
-                yellow_word_update.insert(*c, 0);
-            }
-            self.word.trim().chars().enumerate().for_each(|(pos, c)| { 
-                if guess.chars().nth(pos).unwrap() != c{
-                    let count = yellow_word_nums.entry(c).or_insert(0);
-                    *count += 1;
-                }
-            });
-            guess.chars().enumerate().for_each(|(pos, c)| {
-                let count = yellow_word_nums.entry(c).or_insert(0);
-                let display = if self.word.chars().nth(pos).unwrap() == c {
-                    green_word_update[pos] = true;
-                    self.conditions.insert(c, 'G');
-                    format!("{c}").to_uppercase().bright_green()
-                } else if self.word.chars().any(|wc| wc == c) && *count != 0 {
-                    *count -= 1;
-                    let cnt = yellow_word_update.entry(c).or_insert(0);
-                    *cnt += 1;
-                    if self.conditions.get(&c).unwrap() != &('G'){
-                        self.conditions.insert(c, 'Y');
-                    }
-                    format!("{c}").to_uppercase().bright_yellow()
-                } else {
-                    if self.conditions.get(&c).unwrap() == &('X'){
-                        self.conditions.insert(c, 'R');
-                    }
-                    format!("{c}").to_uppercase().red()
-                };
-                print!("{}", display);
-            });
-            println!();
-            for c in CHAR_LIST {
-                let cnt = yellow_word_update.entry(*c).or_insert(0);
-                let cur = self.yellow_num.entry(*c).or_insert(0);
-                if *cnt > *cur {
-                    *cur = *cnt;
-                }
-            }
-            for i in 0..5 {
-                if green_word_update[i] == true {
-                    self.green_pos[i] = true;
-                }
-                if self.green_pos[i] == true {
-                    let c = self.word.chars().nth(i).unwrap();
-                    let cur = self.yellow_num.entry(c).or_insert(0);
-                    if *cur > 0 {
-                        *cur -= 1;
-                    }
-                }
-            }
-        });
-        println!("The state of all letters are shown below: ");
-        self.display_letters_state();    
+    // In "Absurdle Mode", to start a new game whose answer is chosen as late as possible.
+    fn start_absurdle(&mut self, final_dic: &[String]) {
+        self.boards = vec![Round::adversarial(final_dic, self.difficulty, self.max_tries)];
+        self.reset();
     }
 
-    // In "Test Mode", display the result of each guess.
-    fn display_guesses_test(&mut self, guess: &str) {
-        let mut green_word_update: [bool; 5] = [false, false, false, false, false];
-        let mut yellow_word_update: HashMap<char, u64> = HashMap::new();
-        let mut yellow_word_nums: HashMap<char, u64> = HashMap::new();
-        for c in CHAR_LIST {
-            yellow_word_nums.insert(*c, 0);
-            yellow_word_update.insert(*c, 0);
+    // To reset the hints and the clock of a new game.
+    fn reset(&mut self) {
+        self.hints = 0;
+        self.prefilled = 0;
+        self.started = Instant::now();
+        self.guess_started = self.started;
+        self.guess_times.clear();
+        self.timed_out = false;
+    }
+
+    fn is_timed(&self) -> bool {
+        self.time_limit.is_some() || self.guess_time_limit.is_some()
+    }
+
+    // In "Timed Mode", the time left before the game or the current guess runs out of time.
+    fn time_left(&self) -> Option<Duration> {
+        let game_left = self.time_limit.map(|limit| limit.saturating_sub(self.started.elapsed()));
+        let guess_left = self.guess_time_limit.map(|limit| limit.saturating_sub(self.guess_started.elapsed()));
+        match (game_left, guess_left) {
+            (Some(game_left), Some(guess_left)) => Some(game_left.min(guess_left)),
+            (game_left, guess_left) => game_left.or(guess_left),
         }
-        self.word.trim().chars().enumerate().for_each(|(pos, c)| { 
-            if guess.chars().nth(pos).unwrap() != c{
-                let count = yellow_word_nums.entry(c).or_insert(0);
-                *count += 1;
-            }
-        });
-        guess.chars().enumerate().for_each(|(pos, c)| {
-            let count = yellow_word_nums.entry(c).or_insert(0);
-            let display = if self.word.chars().nth(pos).unwrap() == c {
-                green_word_update[pos] = true;
-                self.conditions.insert(c, 'G');
-                'G'
-            } else if self.word.chars().any(|wc| wc == c) && *count != 0 {
-                *count -= 1;
-                let cnt = yellow_word_update.entry(c).or_insert(0);
-                *cnt += 1;
-                if self.conditions.get(&c).unwrap() != &('G'){
-                    self.conditions.insert(c, 'Y');
-                }
-                'Y'
-            } else {
-                if self.conditions.get(&c).unwrap() == &('X'){
-                    self.conditions.insert(c, 'R');
-                }
-                'R'
-            };
-            print!("{display}");
-        });
-        print!(" ");
-        for c in CHAR_LIST {
-            print!("{}", self.conditions.get(&c).unwrap());
+    }
+
+    // In "Timed Mode", a guess entered too late loses the game.
+    fn check_time(&mut self) -> bool {
+        if self.time_left() == Some(Duration::ZERO) {
+            self.timed_out = true;
         }
-        println!();
-        self.green_pos = green_word_update.clone();
-        self.yellow_num = yellow_word_update.clone();
+        !self.timed_out
     }
 
-    // In "Difficult Mode", display the result of each guess.
-    fn display_guesses_difficult(&mut self) {
-        self.guesses.iter().enumerate().for_each(|(guess_number, guess)| {
-            print!("{}: ", guess_number + 1);
-            let mut green_word_update: [bool; 5] = [false, false, false, false, false];
-            let mut yellow_word_update: HashMap<char, u64> = HashMap::new();
-            let mut yellow_word_nums: HashMap<char, u64> = HashMap::new();
-            for c in CHAR_LIST {
-                yellow_word_nums.insert(*c, 0);
-                yellow_word_update.insert(*c, 0);
-            }
-            self.word.trim().chars().enumerate().for_each(|(pos, c)| { 
-                if guess.chars().nth(pos).unwrap() != c{
-                    let count = yellow_word_nums.entry(c).or_insert(0);
-                    *count += 1;
+    // The board hints and limits refer to: the first one still being played, or the last one.
+    fn current(&self) -> &Round {
+        self.boards.iter().find(|board| !board.is_over()).unwrap_or(&self.boards[self.boards.len() - 1])
+    }
+
+    // All guesses of the game, as recorded by the board played the longest.
+    fn guesses(&self) -> Vec<String> {
+        let longest = self.boards.iter().max_by_key(|board| board.tries()).unwrap();
+        longest.history().iter().map(|(guess, _)| guess.clone()).collect()
+    }
+
+    fn is_won(&self) -> bool {
+        !self.timed_out && self.boards.iter().all(Round::is_won)
+    }
+
+    fn is_over(&self) -> bool {
+        self.timed_out || self.boards.iter().all(Round::is_over)
+    }
+
+    // To check a guess against the rules of every board still being played.
+    fn check_guess(&self, guess: &str, acceptable_dic: &[String]) -> Result<(), GuessError> {
+        self.boards.iter().filter(|board| !board.is_over()).try_for_each(|board| board.check(guess, acceptable_dic))
+    }
+
+    // To score an accepted guess on every board still being played and update the state of all letters.
+    fn record_guess(&mut self, guess: &str) {
+        self.play_guess(guess);
+        self.guess_times.push(self.guess_started.elapsed().as_millis() as u64);
+        self.guess_started = Instant::now();
+    }
+
+    fn play_guess(&mut self, guess: &str) {
+        for board in self.boards.iter_mut().filter(|board| !board.is_over()) {
+            board.play(guess);
+        }
+        if self.counts_in_stats() {
+            *self.used_words.entry(guess.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    // With the parameter "--prefill", replay a guess before the player takes over,
+    // e.g. to practise a position from a real game. Its time is not recorded.
+    // The guess must be accepted by the rules, and must not finish the game.
+    fn prefill_guess(&mut self, guess: &str, acceptable_dic: &[String]) {
+        if let Err(e) = self.check_guess(guess, acceptable_dic) {
+            panic!("The prefilled guess {} is invalid: {}!", guess.to_uppercase(), e);
+        }
+        self.play_guess(guess);
+        self.prefilled += 1;
+        if self.is_over() {
+            panic!("The prefilled guesses must not finish the game!");
+        }
+    }
+
+    // In "Interactive Mode", display the result of each guess, with the boards side by side.
+    // A board shows no more rows once it is over.
+    fn display_guesses(&self) {
+        let n_rows = self.boards.iter().map(Round::tries).max().unwrap_or(0);
+        for row in 0..n_rows {
+            print!("{}: ", row + 1);
+            for (board_number, board) in self.boards.iter().enumerate() {
+                if board_number > 0 {
+                    print!("  ");
                 }
-            });
-            guess.chars().enumerate().for_each(|(pos, c)| {
-                let count = yellow_word_nums.entry(c).or_insert(0);
-                let display = if self.word.chars().nth(pos).unwrap() == c {
-                    green_word_update[pos] = true;
-                    self.conditions.insert(c, 'G');
-                    format!("{c}").to_uppercase().bright_green()
-                } else if self.word.chars().any(|wc| wc == c) && *count != 0 {
-                    *count -= 1;
-                    let cnt = yellow_word_update.entry(c).or_insert(0);
-                    *cnt += 1;
-                    if self.conditions.get(&c).unwrap() != &('G'){
-                        self.conditions.insert(c, 'Y');
+                match board.history().get(row) {
+                    Some((guess, feedback)) => {
+                        for (c, state) in guess.chars().zip(feedback.tiles()) {
+                            print!("{}", paint(c, Some(*state)));
+                        }
                     }
-                    format!("{c}").to_uppercase().bright_yellow()
-                } else {
-                    if self.conditions.get(&c).unwrap() == &('X'){
-                        self.conditions.insert(c, 'R');
-                    }
-                    format!("{c}").to_uppercase().red()
-                };
-                print!("{}", display);
-            });
-            println!();
-            for i in 0..5 {
-                if green_word_update[i] == true {
-                    self.green_pos[i] = true;
+                    None => print!("{}", " ".repeat(self.length)),
                 }
             }
-            self.green_pos = green_word_update.clone();
-            self.yellow_num = yellow_word_update.clone();
-        });
+            println!();
+        }
         println!("The state of all letters are shown below: ");
-        self.display_letters_state();    
+        self.display_letters_state();
+    }
+
+    // In "Test Mode", display the result of the latest guess: a line for each board.
+    // A board that was already over shows dashes instead of a pattern.
+    fn display_guesses_test(&self) {
+        let n_tries = self.boards.iter().map(Round::tries).max().unwrap_or(0);
+        for board in &self.boards {
+            match board.history().last() {
+                Some((_, feedback)) if board.tries() == n_tries => println!("{} {}", feedback, board.keyboard()),
+                _ => println!("{} {}", "-".repeat(self.length), board.keyboard()),
+            }
+        }
     }
 
-    // In "Interactive Mode", display the state of each letter.
+    // In "Interactive Mode", display the state of each letter, combined over all boards.
     fn display_letters_state(&self) {
+        let mut keyboard = KeyboardState::new();
+        for board in &self.boards {
+            keyboard.merge(board.keyboard());
+        }
         for c in CHAR_LIST {
-            if self.conditions.get(&c).unwrap() == &('G') {
-                print!("{} ", format!("{c}").to_uppercase().bright_green());
-            } else if self.conditions.get(&c).unwrap() == &('Y') {
-                print!("{} ", format!("{c}").to_uppercase().bright_yellow());
-            } else if self.conditions.get(&c).unwrap() == &('R') {
-                print!("{} ", format!("{c}").to_uppercase().red());
-            } else {
-                print!("{} ", format!("{c}").to_uppercase());
-            }
+            print!("{} ", paint(*c, keyboard.get(*c)));
         }
         println!();
     }
 
+    // To read the "hint" command with its limit, in both modes.
+    // A word of the list, such as "hint" among 4-letter words, is played rather than taken as a command.
+    fn hint_command(&self, input: &str, acceptable_dic: &[String]) -> Option<usize> {
+        let word = sanitize(input);
+        let is_word = input.trim().eq_ignore_ascii_case(&word) && self.check_guess(&word, acceptable_dic).is_ok();
+        if is_word { None } else { solver::parse_hint(input) }
+    }
+
     // In "Interactive Mode", get the player's input and determine if it is valid.
-    fn ask_for_guess(&mut self, acceptable_dic: &Vec<String>) -> String {
-        println!("{}", format!("Enter your guess (5 letters) and press ENTER: {} tries left", MAX_TRIES - self.guesses.len()).cyan());
-        let mut guess = String::new();
-        let mut valid_guess = false;
-        while !valid_guess {
-            guess = String::new();
-            std::io::stdin().read_line(&mut guess).unwrap();
-            guess = sanitize(&guess);
-            if guess.len() != WORD_LENGTH {
-                if guess == "hint" {
-                    self.word_hint(acceptable_dic);
-                } else {
-                    println!("{}", format!("INVALID! Your guess must be 5 letters.").red())
+    fn ask_for_guess(&mut self, final_dic: &[String], acceptable_dic: &[String]) -> String {
+        println!("{}", format!("Enter your guess ({} letters) and press ENTER: {}", self.length, self.tries_left()).cyan());
+        loop {
+            let mut guess = String::new();
+            if std::io::stdin().read_line(&mut guess).unwrap() == 0 {
+                panic!("No guess is given!");
+            }
+            if !self.check_time() {
+                return guess;
+            }
+            if let Some(limit) = self.hint_command(&guess, acceptable_dic) {
+                self.word_hint(final_dic, acceptable_dic, limit);
+                continue;
+            }
+            let guess = sanitize(&guess);
+            match self.check_guess(&guess, acceptable_dic) {
+                Ok(()) => {
+                    self.record_guess(&guess);
+                    return guess;
                 }
-            } else if !acceptable_dic.iter().any(|word| word==&guess) {
-                println!("{} {} {}", "INVALID! The word".red(), guess.to_uppercase().red(), "isn't in the Wordle dictionary.".red())
-            } else {
-                self.guesses.push(guess.clone());
-                *self.used_words.entry(guess.clone()).or_insert(0) += 1;
-                valid_guess = true;
+                Err(GuessError::NotInDictionary) => {
+                    println!("{} {} {}", "INVALID! The word".red(), guess.to_uppercase().red(), "isn't in the Wordle dictionary.".red())
+                }
+                Err(e) => println!("{}", format!("INVALID! {}.", e).red()),
             }
         }
-        guess
     }
 
     // In "Test Mode", get the player's input and determine if it is valid.
-    fn ask_for_guess_test(&mut self, acceptable_dic: &Vec<String>) -> String {
-        let mut guess = String::new();
-        let mut valid_guess = false;
-        while !valid_guess {
-            guess = String::new();
-            std::io::stdin().read_line(&mut guess).unwrap();
-            guess = sanitize(&guess);
-            if guess.trim().len() != WORD_LENGTH {
-                println!("INVALID")
-            } else if !acceptable_dic.iter().any(|word| word.trim()==&guess) {
-                println!("INVALID")
-            } else {
-                self.guesses.push(guess.clone());
-                *self.used_words.entry(guess.clone()).or_insert(0) += 1;
-                valid_guess = true;
-            }
-        }
-        guess
-    }
-
-    // In "Interactive Difficult Mode", get the player's input and determine if it is valid.
-    fn ask_for_guess_difficult(&mut self, acceptable_dic: &Vec<String>) -> String {
-        println!("{}", format!("Enter your guess (5 letters) and press ENTER: {} tries left", MAX_TRIES - self.guesses.len()).cyan());
-        let mut guess = String::new();
-        let mut valid_guess = false;
-        while !valid_guess {
-            guess = String::new();
-            std::io::stdin().read_line(&mut guess).unwrap();
-            guess = sanitize(&guess);
-            if guess.len() != WORD_LENGTH {
-                if guess == "hint" {
-                    self.word_hint(acceptable_dic);
-                } else {
-                    println!("{}", format!("INVALID! Your guess must be {} letters.", WORD_LENGTH).red())
-                }
-            } else if !acceptable_dic.iter().any(|word| word==&guess) {
-                println!("{} {} {}", "INVALID! The word".red(), guess.to_uppercase().red(), "isn't in the Wordle dictionary.".red())
-            } else if self.check_guess_difficult(&guess) == false {
-                println!("{}", "INVALID! Please ensure that you follow the rules of difficult mode.".red())
-            } else {
-                self.guesses.push(guess.clone());
-                *self.used_words.entry(guess.clone()).or_insert(0) += 1;
-                valid_guess = true;
+    fn ask_for_guess_test(&mut self, final_dic: &[String], acceptable_dic: &[String]) -> String {
+        loop {
+            let mut guess = String::new();
+            if std::io::stdin().read_line(&mut guess).unwrap() == 0 {
+                panic!("No guess is given!");
+            }
+            if !self.check_time() {
+                return guess;
+            }
+            if let Some(limit) = self.hint_command(&guess, acceptable_dic) {
+                self.word_hint_test(final_dic, acceptable_dic, limit);
+                continue;
+            }
+            let guess = sanitize(&guess);
+            if self.check_guess(&guess, acceptable_dic).is_ok() {
+                self.record_guess(&guess);
+                return guess;
             }
+            println!("INVALID")
         }
-        guess
     }
-    
-    // In "Test Difficult Mode", get the player's input and determine if it is valid.
-    fn ask_for_guess_test_difficult(&mut self, acceptable_dic: &Vec<String>) -> String {
-        let mut guess = String::new();
-        let mut valid_guess = false;
-        while !valid_guess {
-            guess = String::new();
-            std::io::stdin().read_line(&mut guess).unwrap();
-            guess = sanitize(&guess);
-            if guess.len() != WORD_LENGTH {
-                println!("INVALID")
-            } else if !acceptable_dic.iter().any(|word| word.trim()==&guess) {
-                println!("INVALID")
-            } else if self.check_guess_difficult(&guess) == false {
-                println!("INVALID")
+
+    // To read the answer of a game until it has the right length.
+    fn ask_for_answer(&self, is_tty: bool) -> String {
+        loop {
+            if is_tty {
+                println!("Input a word as the answer of this Wordle Game: ");
+            }
+            let mut read_word = String::new();
+            if std::io::stdin().read_line(&mut read_word).unwrap() == 0 {
+                panic!("No answer is given!");
+            }
+            let word = sanitize(&read_word);
+            if word.len() == self.length {
+                return word;
+            }
+            if is_tty {
+                println!("{}", format!("INVALID! The answer must be {} letters.", self.length).red());
             } else {
-                self.guesses.push(guess.clone());
-                *self.used_words.entry(guess.clone()).or_insert(0) += 1;
-                valid_guess = true;
+                println!("INVALID");
             }
         }
-        guess
     }
 
-    // In "Random Mode", determine if the player has guessed correctly.
-    fn is_game_over(&mut self, guess: &str) -> bool {
+    // To describe the remaining tries in the prompt, and the remaining time in "Timed Mode".
+    fn tries_left(&self) -> String {
+        let tries_left = match self.current().tries_left() {
+            Some(tries_left) => format!("{} tries left", tries_left),
+            None => "unlimited tries".to_string(),
+        };
+        match self.time_left() {
+            Some(time_left) => format!("{}, {}s left", tries_left, time_left.as_secs_f64().ceil()),
+            None => tries_left,
+        }
+    }
+
+    // In "Interactive Mode", determine if the player has guessed correctly.
+    // In "Anti Mode", the game is over once the player hits the answer.
+    fn is_game_over(&mut self) -> bool {
         self.display_guesses();
-        let n_tries = self.guesses.len();
-        if guess.to_string().trim() == self.word.trim() {
-            println!("CORRECT! You guessed the word in {} tries.", n_tries);
-            self.win += 1;
-            self.tries += n_tries as u64;
+        let n_tries = self.guesses().len();
+        if self.anti {
+            if !self.is_over() {
+                return false;
+            }
+            let survived = self.boards[0].survived();
+            if self.timed_out {
+                println!("{}", format!("TIME UP! You survived {} guesses.", survived).bright_green());
+            } else {
+                println!("{}", format!("BOOM! The word was {}. You survived {} guesses.", self.boards[0].answer(), survived).bright_red());
+            }
+            self.record_survival(survived);
             true
-        } else if n_tries >= MAX_TRIES {
-            println!("{}", format!("SHAME! You ran out of tries! The word was {}", self.word).bright_red().trim());
-            self.lose += 1;
+        } else if self.is_won() {
+            if self.boards.len() == 1 {
+                println!("CORRECT! You guessed the word in {} tries.", n_tries);
+            } else {
+                println!("CORRECT! You guessed all {} words in {} tries.", self.boards.len(), n_tries);
+            }
+            self.record_win(n_tries);
+            true
+        } else if self.is_over() {
+            let reason = if self.timed_out { "time" } else { "tries" };
+            let answers: Vec<&str> = self.boards.iter().filter(|board| !board.is_won()).flat_map(Round::answers).collect();
+            if answers.len() == 1 {
+                println!("{}", format!("SHAME! You ran out of {}! The word was {}", reason, answers[0]).bright_red().trim());
+            } else {
+                println!("{}", format!("SHAME! You ran out of {}! The words were {}", reason, answers.join(", ")).bright_red().trim());
+            }
+            self.record_loss();
             true
         } else { false }
     }
 
-    // In "Difficult Mode", get the player's input and determine if it is valid.
-    fn is_game_over_difficult(&mut self, guess: &str) -> bool {
-        self.display_guesses_difficult();
-        let n_tries = self.guesses.len();
-        if guess.to_string().trim() == self.word.trim() {
-            println!("CORRECT! You guessed the word in {} tries!", n_tries);
-            self.win += 1;
-            self.tries += n_tries as u64;
-            true
-        } else if n_tries >= MAX_TRIES {
-            println!("{}", format!("WRONG! You ran out of tries! The word was {}.", self.word).bright_red().trim());
-            self.lose += 1;
-            true
-        } else { false }
+    // With the parameter "--source", only the games whose answers are chosen that way are counted.
+    fn counts_in_stats(&self) -> bool {
+        self.stats_source.as_deref().is_none_or(|source| source == self.source)
     }
 
-    // In "Test Mode", get the player's input and determine if it is valid.
-    fn is_game_over_test(&mut self, guess: &str) -> bool {
-        let n_tries = self.guesses.len();
-        if guess.to_string().trim() == self.word.trim() {
+    // Whether a game of the state file counts in the statistics: only the games of the source given by "--source",
+    // and of the player given by "--player" or the name entered, if any.
+    fn counts_history(&self, single_game: &Game) -> bool {
+        self.counts_source(single_game) && (self.player.is_none() || single_game.player == self.player)
+    }
+
+    // Whether a game of the state file is of the source given by "--source", if any.
+    fn counts_source(&self, single_game: &Game) -> bool {
+        self.stats_source.as_ref().is_none_or(|source| *source == single_game.source)
+    }
+
+    // Whether a game of the state file was played with the current words, as needed to count its possible answers again.
+    // Only the length of the answer is known of games without a word list.
+    fn played_with_words(&self, single_game: &Game) -> bool {
+        single_game.answer.len() == self.length && single_game.word_list.as_ref().is_none_or(|word_list| *word_list == self.word_list)
+    }
+
+    // To count a game of the state file in the statistics.
+    fn add_history(&mut self, single_game: &Game) {
+        for guess in &single_game.guesses {
+            *self.used_words.entry(guess.to_lowercase()).or_insert(0) += 1;
+        }
+        // Games of "Anti Mode" are neither won nor lost.
+        if let Some(survived) = single_game.survived {
+            self.anti_scores.push(survived);
+        } else if single_game.is_win() {
             self.win += 1;
-            self.tries += n_tries as u64;
-            println!("CORRECT {}", n_tries);
-            true
-        } else if n_tries >= MAX_TRIES {
+            if let Some(time) = single_game.time {
+                self.solve_times.push(time);
+            }
+            // Only games with the same limit are counted in the average tries.
+            if single_game.max_tries == self.max_tries {
+                self.tries += single_game.guesses.len() as u64;
+                self.tries_win += 1;
+            }
+        } else {
             self.lose += 1;
-            println!("{}", format!("FAILED {}", self.word.to_uppercase()).bright_red().trim());
-            true
-        } else { false }
+        }
     }
 
-    // With the parameter "-t/--stats", print relevant statistics.
-    fn print_info(&mut self) {
-        print!("{} ", self.win);
-        print!("{} ", self.lose);
-        if self.win == 0 { println!("0.00"); }
-        else {
-            let average: f32 = self.tries as f32 / self.win as f32;
-            println!("{:.2}", average);
-        }
+    fn average_tries(&self) -> f32 {
+        if self.tries_win == 0 { 0.0 } else { self.tries as f32 / self.tries_win as f32 }
+    }
+
+    // Sort by the times used in descending order, then in alphabetical order.
+    fn top_words(&self) -> Vec<(&String, &u64)> {
         let mut cnt_words: Vec<(&String, &u64)> = self.used_words.iter().collect();
-        cnt_words.sort_by(|a, b| b.1.cmp(a.1));
-        let length = cnt_words.len();
-        let mut index: Vec<usize> = Vec::new();
-        index.push(0);
-        for i in 0..(length - 1) {
-            if cnt_words[i].1 != cnt_words[i + 1].1 {
-                index.push(i + 1);
-            }
+        cnt_words.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        cnt_words.truncate(TOP_WORDS);
+        cnt_words
+    }
+
+    fn record_win(&mut self, n_tries: usize) {
+        if !self.counts_in_stats() {
+            return;
         }
-        index.push(length);
-        for i in 0..index.len() - 1 {
-            let slice = &mut cnt_words[index[i]..index[i + 1]];
-            slice.sort_by(|a, b| a.0.cmp(b.0));
+        self.win += 1;
+        self.tries += n_tries as u64;
+        self.tries_win += 1;
+        if self.is_timed() {
+            self.solve_times.push(self.guess_times.iter().sum());
         }
-        if length <= WORD_LENGTH {
-            for i in 0..(length - 1) {
-                print!("{} {} ", cnt_words[i].0.to_uppercase(), cnt_words[i].1);
-            }
-            print!("{} {}", cnt_words[length - 1].0.to_uppercase(), cnt_words[length - 1].1);
+    }
+
+    fn record_loss(&mut self) {
+        if self.counts_in_stats() {
+            self.lose += 1;
         }
-        else {
-            for i in 0..4 {
-                print!("{} {} ", cnt_words[i].0.to_uppercase(), cnt_words[i].1);
-            }
-            print!("{} {}", cnt_words[4].0.to_uppercase(), cnt_words[4].1);
+    }
+
+    fn record_survival(&mut self, survived: usize) {
+        if self.counts_in_stats() {
+            self.anti_scores.push(survived as u64);
         }
-        println!();
     }
 
-    // In "Difficult Mode", check if the guessed word follows the rules.
-    fn check_guess_difficult(&mut self, guess: &str) -> bool {
-        for c in 0..WORD_LENGTH {
-            if self.green_pos[c] == true {
-                if guess.trim().chars().nth(c).unwrap() != self.word.trim().chars().nth(c).unwrap() {
-                    return false;
-                }
-            }
+    // In "Test Mode", determine if the player has guessed correctly.
+    // Once every board is over, print a line for each board.
+    fn is_game_over_test(&mut self) -> bool {
+        if !self.is_over() {
+            return false;
         }
-        let mut check_yellow_num = self.yellow_num.clone();
-        for c in CHAR_LIST {
-            let cnt = check_yellow_num.entry(*c).or_insert(0);
-            for i in 0..WORD_LENGTH {
-                if guess.trim().chars().nth(i).unwrap() == *c && self.green_pos[i] == false {
-                    if *cnt > 0 { *cnt -= 1; }
-                }
+        if self.anti {
+            let survived = self.boards[0].survived();
+            println!("SURVIVED {}", survived);
+            self.record_survival(survived);
+            return true;
+        }
+        for board in &self.boards {
+            if board.is_won() {
+                println!("CORRECT {}", board.tries());
+            } else {
+                println!("{}", format!("FAILED {}", board.answers().join(" ").to_uppercase()).bright_red().trim());
             }
-            if *cnt > 0 { return false; }
+        }
+        if self.is_won() {
+            self.record_win(self.guesses().len());
+        } else {
+            self.record_loss();
         }
         true
     }
-    
-    // Used to give hints.
-    fn check_guess_hint(&mut self, guess: &str) -> bool {
-        for c in 0..WORD_LENGTH {
-            if self.green_pos[c] == true {
-                if guess.trim().chars().nth(c).unwrap() != self.word.trim().chars().nth(c).unwrap() {
-                    return false;
-                }
-            }
-            let c = guess.trim().chars().nth(c).unwrap();
-            if self.conditions.get(&c).unwrap() == &('R') {
-                return false;
-            }
+
+    // With the parameter "-t/--stats", print relevant statistics.
+    fn print_info(&self) {
+        print!("{} ", self.win);
+        print!("{} ", self.lose);
+        println!("{:.2}", self.average_tries());
+        let top_words: Vec<String> = self.top_words().iter()
+            .map(|(word, cnt)| format!("{} {}", word.to_uppercase(), cnt))
+            .collect();
+        println!("{}", top_words.join(" "));
+        // In "Timed Mode", the best and average times of the games won, in seconds.
+        if let Some(best) = self.solve_times.iter().min() {
+            let average = self.solve_times.iter().sum::<u64>() as f64 / self.solve_times.len() as f64;
+            println!("{:.2} {:.2}", *best as f64 / 1000.0, average / 1000.0);
         }
-        let mut check_yellow_num = self.yellow_num.clone();
-        for c in CHAR_LIST {
-            let cnt = check_yellow_num.entry(*c).or_insert(0);
-            for i in 0..WORD_LENGTH {
-                if guess.trim().chars().nth(i).unwrap() == *c && self.green_pos[i] == false {
-                    if *cnt > 0 { *cnt -= 1; }
-                }
-            }
-            if *cnt > 0 { return false; }
+        // In "Anti Mode", the number of games, then the best and average numbers of guesses survived.
+        if let Some(best) = self.anti_scores.iter().max() {
+            let average = self.anti_scores.iter().sum::<u64>() as f64 / self.anti_scores.len() as f64;
+            println!("{} {} {:.2}", self.anti_scores.len(), best, average);
         }
-        true
     }
-    
+
     // In "Interactive Mode", execute the game.
-    fn execute_game(&mut self, game_config: &Configuration, acceptable_dic: &Vec<String>) {
+    fn execute_game(&mut self, game_config: &Configuration, final_dic: &[String], acceptable_dic: &[String]) {
+        if !game_config.prefill.is_empty() {
+            for guess in parse_words(&game_config.prefill) {
+                self.prefill_guess(&guess, acceptable_dic);
+            }
+            self.display_guesses();
+            println!();
+        }
         loop {
-            let mut guess = String::new();
-            if game_config.difficult == false {
-                guess = self.ask_for_guess(acceptable_dic);
-                if self.is_game_over(&guess) {
-                    if game_config.stats == true {
-                        self.print_info();
-                    }
-                    break;
-                }
-                println!();
-            } else {
-                guess = self.ask_for_guess_difficult(acceptable_dic);
-                if self.is_game_over_difficult(&guess) {
-                    if game_config.stats == true {
-                        self.print_info();
-                    }
-                    break;
+            self.ask_for_guess(final_dic, acceptable_dic);
+            if self.is_game_over() {
+                if game_config.stats {
+                    self.print_info();
                 }
-                println!();
+                break;
             }
+            println!();
         }
     }
-    
+
     // In "Test Mode", execute the game.
-    fn execute_game_test(&mut self, game_config: &Configuration, acceptable_dic: &Vec<String>) {
+    // The prefilled guesses are displayed as if they had just been entered.
+    fn execute_game_test(&mut self, game_config: &Configuration, final_dic: &[String], acceptable_dic: &[String]) {
+        if !game_config.prefill.is_empty() {
+            for guess in parse_words(&game_config.prefill) {
+                self.prefill_guess(&guess, acceptable_dic);
+                self.display_guesses_test();
+            }
+        }
         loop {
-            let mut guess = String::new();
-            if game_config.difficult == false {
-                guess = self.ask_for_guess_test(acceptable_dic);
-            } else {
-                guess = self.ask_for_guess_test_difficult(acceptable_dic);
+            self.ask_for_guess_test(final_dic, acceptable_dic);
+            if !self.timed_out {
+                self.display_guesses_test();
             }
-            self.display_guesses_test(&guess);
-            if self.is_game_over_test(&guess) {
-                if game_config.stats == true {
+            if self.is_game_over_test() {
+                if game_config.stats {
                     self.print_info();
                 }
                 break;
@@ -632,53 +970,987 @@ impl WordleGame {
         }
     }
 
-    fn color_initialization(&mut self) {
-        for c in CHAR_LIST { 
-            self.conditions.insert(*c, 'X');
-            self.yellow_num.insert(*c, 0); 
+    // The possible answers of the board played.
+    fn candidates(&self, final_dic: &[String]) -> Vec<String> {
+        let history = self.current().history();
+        if self.current().is_fibble() {
+            solver::filter_candidates_with_lies(final_dic, history)
+        } else {
+            solver::filter_candidates(final_dic, history)
         }
     }
 
-    // In "Interactive Mode", give hints about the answer word.
-    fn word_hint(&mut self, acceptable_dic: &Vec<String>) {
-        let mut ans = Vec::new();
-        for possible_word in acceptable_dic {
-            if self.check_guess_hint(&possible_word) {
-                ans.push(possible_word.to_uppercase().clone());
+    // In "Interactive Mode", give hints about the answer word, see "solver::hints".
+    fn word_hint(&mut self, final_dic: &[String], acceptable_dic: &[String], limit: usize) {
+        self.hints += 1;
+        let history = self.current().history();
+        if self.xordle {
+            let pairs = xordle::filter_pairs(&xordle::disjoint_pairs(final_dic), history);
+            println!("Here are {} of {} possible pairs of words to solve the Wordle game:", pairs.len().min(limit), pairs.len());
+            println!();
+            for (first, second) in pairs.iter().take(limit) {
+                println!("{} {}", first.to_uppercase(), second.to_uppercase());
             }
+            println!();
+            return;
         }
-        println!("Here are {} possible words to solve the Wordle game:", ans.len());
+        let candidates = self.candidates(final_dic);
+        let guesses = solver::allowed_guesses(acceptable_dic, history, self.difficulty);
+        let hints = solver::hints(&candidates, &guesses, limit);
+        println!("Here are {} of {} possible words to solve the Wordle game:", hints.answers.len(), candidates.len());
         println!();
-        for possible_word in ans {
-            println!("{}", possible_word);
+        for guess in &hints.answers {
+            println!("{} ({:.2} bits)", guess.word.to_uppercase(), guess.entropy);
+        }
+        if !hints.probes.is_empty() {
+            println!();
+            println!("These words cannot be the answer, but tell the possible words apart better:");
+            println!();
+            for guess in &hints.probes {
+                println!("{} ({:.2} bits)", guess.word.to_uppercase(), guess.entropy);
+            }
         }
         println!();
     }
-}
-
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialization of Wordle Game, Command Line Parameters and Configuration Parameters.
-    let mut game = WordleGame::new();
-    let yml = clap::load_yaml!("yaml.yml");
-    let matches = App::from_yaml(yml).get_matches();
-    let mut game_config = Configuration::new();
 
-    // Initialization of available words.
-    let mut final_dictionary: Vec<String> = builtin_words::FINAL.iter().map(|&x| x.to_string()).collect();
-    let mut acceptable_dictionary: Vec<String> = builtin_words::ACCEPTABLE.iter().map(|&x| x.to_string()).collect();
-    
-    // Deal with parameter "-c".
-    if let Some(path) = matches.value_of("load_configuration"){
-        if let Err(e) = File::open(path) { panic!("{}", e); }
-        let filename = File::open(path).unwrap();
-        let config: Configuration = serde_json::from_reader(filename).unwrap();
-        game_config = config.clone(); 
+    // In "Test Mode", give the same hints: a line of possible answers, then a line of other words if any.
+    // In "Xordle Mode", the pairs of answers are given as "FIRST/SECOND".
+    fn word_hint_test(&mut self, final_dic: &[String], acceptable_dic: &[String], limit: usize) {
+        self.hints += 1;
+        let history = self.current().history();
+        let upper = |guesses: &[RankedGuess]| guesses.iter().map(|guess| guess.word.to_uppercase()).collect::<Vec<String>>().join(" ");
+        if self.xordle {
+            let pairs: Vec<String> = xordle::filter_pairs(&xordle::disjoint_pairs(final_dic), history).iter()
+                .take(limit)
+                .map(|(first, second)| format!("{}/{}", first.to_uppercase(), second.to_uppercase()))
+                .collect();
+            println!("HINTS {}", pairs.join(" "));
+            return;
+        }
+        let guesses = solver::allowed_guesses(acceptable_dic, history, self.difficulty);
+        let hints = solver::hints(&self.candidates(final_dic), &guesses, limit);
+        println!("HINTS {}", upper(&hints.answers));
+        if !hints.probes.is_empty() {
+            println!("PROBES {}", upper(&hints.probes));
+        }
+    }
+}
+
+// To shuffle the answers with the seed given by "-s".
+// In "Xordle Mode", the pairs of answers are shuffled instead, and listed one after the other.
+fn shuffle_answers(final_dic: &[String], game_config: &Configuration) -> Vec<String> {
+    let mut rng = StdRng::seed_from_u64(game_config.seed);
+    if game_config.xordle {
+        let mut pairs = xordle::disjoint_pairs(final_dic);
+        if pairs.is_empty() {
+            panic!("There are no two answers without any letter in common!");
+        }
+        pairs.shuffle(&mut rng);
+        return pairs.into_iter().flat_map(|(first, second)| [first, second]).collect();
+    }
+    let mut array = final_dic.to_vec();
+    array.shuffle(&mut rng);
+    array
+}
+
+// To bring a state file up to "STATE_VERSION".
+// Version 1 adds fields to every game: only the outcome can be derived for older games, and nothing is removed.
+fn migrate_state(game_json: &mut Games) {
+    if game_json.version == 0 {
+        for single_game in &mut game_json.games {
+            single_game.outcome = single_game.derive_outcome().to_string();
+        }
+        game_json.version = 1;
+    }
+}
+
+// To write a state file as an older version, leaving out the fields it does not have.
+fn downgrade_state(game_json: &mut Games, format_version: u32) {
+    if format_version == 0 {
+        for single_game in &mut game_json.games {
+            single_game.timestamp = None;
+            single_game.player = None;
+            single_game.difficulty = None;
+            single_game.word_list = None;
+            single_game.seed = None;
+            single_game.day = None;
+            single_game.outcome.clear();
+        }
+    }
+    game_json.version = format_version;
+}
+
+// To read the state file given by "-S", or its backup if it is damaged, as the latest version.
+// A file newer than the version to write is refused, since writing it back would lose what it holds.
+fn load_state(path: &str, format_version: u32) -> Games {
+    let mut game_json: Games = match state_file::load(path) {
+        Ok((game_json, recovered)) => {
+            if recovered {
+                eprintln!("{}", format!("The state file {} is damaged, its backup is used instead.", path).yellow());
+            }
+            game_json
+        }
+        Err(e) => panic!("{}", e),
+    };
+    if game_json.version > format_version {
+        panic!("The state file {} has format version {}, newer than version {}! Refusing to downgrade it.", path, game_json.version, format_version);
+    }
+    migrate_state(&mut game_json);
+    game_json
+}
+
+// To write the state file given by "-S" as the given version, keeping the previous one as a backup.
+fn save_state(path: &str, game_json: &mut Games, format_version: u32) -> io::Result<()> {
+    downgrade_state(game_json, format_version);
+    state_file::save(path, game_json)
+}
+
+// To identify custom word lists by a hash of their words (FNV-1a), or "builtin".
+fn word_list_identity(final_dic: &[String], acceptable_dic: &[String], custom: bool) -> String {
+    if !custom {
+        return "builtin".to_string();
+    }
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for word in final_dic.iter().chain([String::new()].iter()).chain(acceptable_dic) {
+        for byte in word.trim().bytes().chain([b'\n']) {
+            hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("custom:{:016x}", hash)
+}
+
+// To append the game just finished to the database given by "--db", or the state file given by "-S".
+// The state file is locked from reading to writing, so that games finished at the same time are all kept.
+fn record_game(game_config: &Configuration, game: &WordleGame) -> io::Result<()> {
+    let mut single_game = new_record(game);
+    single_game.answer = game.boards[0].answer().to_uppercase();
+    single_game.guesses = game.guesses().iter().map(|x| x.to_uppercase()).collect();
+    let answers: Vec<String> = game.boards.iter().flat_map(Round::answers).map(str::to_uppercase).collect();
+    if answers.len() > 1 {
+        single_game.answers = answers;
+    }
+    single_game.lies = game.boards[0].lies().to_vec();
+    if game.is_timed() {
+        single_game.guess_times = game.guess_times.clone();
+        single_game.time = Some(game.guess_times.iter().sum());
+    }
+    if game.prefilled > 0 {
+        single_game.prefilled = Some(game.prefilled);
+    }
+    single_game.xordle = game.xordle;
+    if game.anti {
+        single_game.survived = Some(game.boards[0].survived() as u64);
+    }
+    save_record(game_config, single_game)
+}
+
+// In "Reverse Mode", to append the puzzle just finished to the history: the words entered and the rows to give.
+fn record_reverse(game_config: &Configuration, game: &WordleGame, puzzle: &Puzzle) -> io::Result<()> {
+    let mut single_game = new_record(game);
+    single_game.answer = puzzle.answer().to_uppercase();
+    single_game.guesses = puzzle.entries().iter().map(|(entry, _)| entry.to_uppercase()).collect();
+    single_game.rows = puzzle.rows().iter().map(Feedback::to_string).collect();
+    save_record(game_config, single_game)
+}
+
+// The fields of a finished game shared by every mode: how, when and by whom it was played.
+fn new_record(game: &WordleGame) -> Game {
+    let mut single_game: Game = Game::new();
+    single_game.max_tries = game.max_tries;
+    single_game.hints = game.hints;
+    single_game.date = game.date.clone();
+    single_game.source = game.source.to_string();
+    single_game.timestamp = Some(now());
+    single_game.player = game.player.clone();
+    single_game.difficulty = Some(game.difficulty);
+    single_game.word_list = Some(game.word_list.clone());
+    single_game.seed = game.seed;
+    single_game.day = game.day;
+    single_game
+}
+
+// To append a finished game to the database given by "--db", or else to the state file.
+fn save_record(game_config: &Configuration, mut single_game: Game) -> io::Result<()> {
+    single_game.outcome = single_game.derive_outcome().to_string();
+    if !game_config.db.is_empty() {
+        return append_db(&game_config.db, &[single_game], 1);
+    }
+    let path = &game_config.state;
+    let _lock = state_file::lock(path)?;
+    let mut game_json = load_state(path, game_config.state_format_version);
+    game_json.total_rounds += 1;
+    game_json.games.push(single_game);
+    save_state(path, &mut game_json, game_config.state_format_version)
+}
+
+// To read the history given by "--db" or "-S".
+fn load_history(game_config: &Configuration, format_version: u32) -> Games {
+    if game_config.db.is_empty() {
+        load_state(&game_config.state, format_version)
+    } else {
+        load_db(&game_config.db)
+    }
+}
+
+// To keep the best runs of each rule and number of tries.
+fn keep_high_scores(runs: &mut Vec<SurvivalScore>) {
+    runs.sort_by_key(|run| std::cmp::Reverse(run.score));
+    let mut kept: HashMap<(String, usize), usize> = HashMap::new();
+    runs.retain(|run| {
+        let count = kept.entry((run.rule.clone(), run.max_tries)).or_insert(0);
+        *count += 1;
+        *count <= HIGH_SCORES
+    });
+}
+
+#[cfg(feature = "sqlite")]
+fn open_db(path: &str) -> io::Result<GameDb> {
+    GameDb::open(path).map_err(io::Error::other)
+}
+
+// To read the database given by "--db" as a state file. Its games are always of the latest version.
+#[cfg(feature = "sqlite")]
+fn load_db(path: &str) -> Games {
+    let read = || -> io::Result<Games> {
+        let total_rounds = open_db(path)?.total_rounds().map_err(io::Error::other)?;
+        Ok(Games { version: STATE_VERSION, total_rounds, games: load_db_games(path, None, None), survival: load_survival_db(path)? })
+    };
+    match read() {
+        Ok(game_json) => game_json,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+// To read the games of a player and of a source from the database given by "--db", using its indexes.
+#[cfg(feature = "sqlite")]
+fn load_db_games(path: &str, player: Option<&str>, source: Option<&str>) -> Vec<Game> {
+    let read = || -> Result<Vec<Game>, Box<dyn std::error::Error>> {
+        let mut games = Vec::new();
+        for data in GameDb::open(path)?.games_of(player, source)? {
+            games.push(serde_json::from_str(&data)?);
+        }
+        Ok(games)
+    };
+    match read() {
+        Ok(games) => games,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn db_has_daily(path: &str, date: &str, player: Option<&str>) -> io::Result<bool> {
+    open_db(path)?.has_daily(date, player).map_err(io::Error::other)
+}
+
+#[cfg(feature = "sqlite")]
+fn load_survival_db(path: &str) -> io::Result<Vec<SurvivalScore>> {
+    let runs = open_db(path)?.survival().map_err(io::Error::other)?;
+    Ok(runs.into_iter().map(|run| SurvivalScore { rule: run.rule, score: run.score, max_tries: run.max_tries }).collect())
+}
+
+// To append games to the database given by "--db", with the columns they are queried by.
+// The date of a game is the date of its daily puzzle, or the day it was played.
+#[cfg(feature = "sqlite")]
+fn append_db(path: &str, games: &[Game], rounds: u64) -> io::Result<()> {
+    open_db(path)?.append(&game_rows(games)?, rounds).map_err(io::Error::other)
+}
+
+// To append the games not in the database yet, returning how many were appended.
+#[cfg(feature = "sqlite")]
+fn import_db(path: &str, games: &[Game], rounds: u64) -> io::Result<usize> {
+    open_db(path)?.import(&game_rows(games)?, rounds).map_err(io::Error::other)
+}
+
+// The columns of the games queried by the statistics, next to their JSON.
+#[cfg(feature = "sqlite")]
+fn game_rows(games: &[Game]) -> io::Result<Vec<GameRow>> {
+    let mut rows = Vec::new();
+    for single_game in games {
+        rows.push(GameRow {
+            player: single_game.player.clone(),
+            date: single_game.date.clone().or_else(|| single_game.timestamp.map(|time| daily::format_date(daily::day_of(time)))),
+            outcome: single_game.derive_outcome().to_string(),
+            source: single_game.source.clone(),
+            tries: single_game.guesses.len(),
+            data: serde_json::to_string(single_game)?,
+        });
+    }
+    Ok(rows)
+}
+
+#[cfg(feature = "sqlite")]
+fn save_survival_db(path: &str, runs: &[SurvivalScore]) -> io::Result<()> {
+    let rows: Vec<SurvivalRow> = runs.iter()
+        .map(|run| SurvivalRow { rule: run.rule.clone(), score: run.score, max_tries: run.max_tries })
+        .collect();
+    open_db(path)?.set_survival(&rows).map_err(io::Error::other)
+}
+
+// Without SQLite support, "--db" is refused before any of these is called.
+#[cfg(not(feature = "sqlite"))]
+fn load_db(_path: &str) -> Games {
+    panic!("{}", NO_SQLITE)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn load_db_games(_path: &str, _player: Option<&str>, _source: Option<&str>) -> Vec<Game> {
+    panic!("{}", NO_SQLITE)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn db_has_daily(_path: &str, _date: &str, _player: Option<&str>) -> io::Result<bool> {
+    panic!("{}", NO_SQLITE)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn load_survival_db(_path: &str) -> io::Result<Vec<SurvivalScore>> {
+    panic!("{}", NO_SQLITE)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn append_db(_path: &str, _games: &[Game], _rounds: u64) -> io::Result<()> {
+    panic!("{}", NO_SQLITE)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn save_survival_db(_path: &str, _runs: &[SurvivalScore]) -> io::Result<()> {
+    panic!("{}", NO_SQLITE)
+}
+
+// In "Survival Mode", play consecutive answers of the shuffled list until a game is lost.
+// With the rule "carry", the tries left after a game are added to the next one;
+// with the rule "shrink", every game has one try less than the one before, down to a single try.
+fn execute_survival(game: &mut WordleGame, game_config: &Configuration, final_dic: &[String], acceptable_dic: &[String], is_tty: bool) -> io::Result<()> {
+    let array = shuffle_answers(final_dic, game_config);
+    let mut day = game_config.day as usize - 1;
+    let mut score: u64 = 0;
+    loop {
+        let words: Vec<String> = (0..game_config.answers_per_game())
+            .map(|board| array[(day * game_config.answers_per_game() + board) % array.len()].clone())
+            .collect();
+        game.seed = Some(game_config.seed);
+        game.day = Some(day as u64 + 1);
+        game.start(&words);
+        if is_tty {
+            println!("{}", format!("Puzzle {}: {} tries", score + 1, game.max_tries).cyan());
+            game.execute_game(game_config, final_dic, acceptable_dic);
+            println!();
+        } else {
+            game.execute_game_test(game_config, final_dic, acceptable_dic);
+        }
+        if game_config.keeps_history() {
+            record_game(game_config, game)?;
+        }
+        if !game.is_won() {
+            break;
+        }
+        score += 1;
+        day += 1;
+        game.max_tries = if game_config.survival == "carry" {
+            game_config.max_tries + game.max_tries - game.guesses().len()
+        } else {
+            game_config.max_tries.saturating_sub(score as usize).max(1)
+        };
+    }
+    game.max_tries = game_config.max_tries;
+    if is_tty {
+        println!("GAME OVER! You cleared {} puzzles.", score);
+    } else {
+        println!("SCORE {}", score);
+    }
+
+    // Keep the best runs of each rule and number of tries, then print those of this run.
+    if game_config.keeps_history() {
+        let run = SurvivalScore { rule: game_config.survival.clone(), score, max_tries: game_config.max_tries };
+        let runs = if !game_config.db.is_empty() {
+            let mut runs = load_survival_db(&game_config.db)?;
+            runs.push(run);
+            keep_high_scores(&mut runs);
+            save_survival_db(&game_config.db, &runs)?;
+            runs
+        } else {
+            let _lock = state_file::lock(&game_config.state)?;
+            let mut game_json = load_state(&game_config.state, game_config.state_format_version);
+            game_json.survival.push(run);
+            keep_high_scores(&mut game_json.survival);
+            save_state(&game_config.state, &mut game_json, game_config.state_format_version)?;
+            game_json.survival
+        };
+        let scores: Vec<String> = runs.iter()
+            .filter(|run| run.rule == game_config.survival && run.max_tries == game_config.max_tries)
+            .map(|run| run.score.to_string())
+            .collect();
+        if is_tty {
+            println!("High scores: {}", scores.join(" "));
+        } else {
+            println!("HIGH SCORES {}", scores.join(" "));
+        }
+    }
+    Ok(())
+}
+
+// In "Daily Mode", play the puzzle of the given number, taken from the list shuffled with the seed given by "-s".
+fn execute_daily(game: &mut WordleGame, game_config: &Configuration, number: i64, final_dic: &[String], acceptable_dic: &[String], is_tty: bool) -> io::Result<()> {
+    let array = shuffle_answers(final_dic, game_config);
+    let index = daily::puzzle_index(number, array.len());
+    let words: Vec<String> = (0..game_config.answers_per_game())
+        .map(|board| array[(index * game_config.answers_per_game() + board) % array.len()].clone())
+        .collect();
+    game.seed = Some(game_config.seed);
+    game.start(&words);
+    if is_tty {
+        println!("{}", format!("Daily puzzle #{} ({})", number, game.date.as_deref().unwrap_or_default()).cyan());
+        game.execute_game(game_config, final_dic, acceptable_dic);
+    } else {
+        println!("PUZZLE {}", number);
+        game.execute_game_test(game_config, final_dic, acceptable_dic);
+    }
+    if game_config.keeps_history() {
+        record_game(game_config, game)?;
+    }
+    Ok(())
+}
+
+// In "Reverse Mode", play puzzles showing the answer and the rows to give against it.
+// The rows come from "--rows", or are picked with the seed given by "-s" so that every row has a solution.
+fn execute_reverse(game: &mut WordleGame, game_config: &Configuration, final_dic: &[String], acceptable_dic: &[String], is_tty: bool) -> io::Result<()> {
+    let mut rng = StdRng::seed_from_u64(game_config.seed);
+    let array = shuffle_answers(final_dic, game_config);
+    let mut day = game_config.day as usize - 1;
+    loop {
+        let answer = if !game_config.word.is_empty() {
+            parse_words(&game_config.word)[0].clone()
+        } else if game_config.random {
+            array[day % array.len()].clone()
+        } else {
+            game.ask_for_answer(is_tty)
+        };
+        let rows = if game_config.rows.is_empty() {
+            reverse::generate(&answer, acceptable_dic, REVERSE_ROWS, &mut rng)
+        } else {
+            parse_rows(&game_config.rows).unwrap()
+        };
+        let unsolvable: Vec<String> = reverse::unsolvable_rows(&answer, &rows, acceptable_dic).iter()
+            .map(|row| format!("{} ({})", row + 1, rows[*row]))
+            .collect();
+        if !unsolvable.is_empty() {
+            panic!("No word gives row {} against {}!", unsolvable.join(", "), answer.to_uppercase());
+        }
+        let mut puzzle = Puzzle::new(&answer, rows, game.max_tries);
+        if is_tty {
+            play_reverse(&mut puzzle, acceptable_dic);
+        } else {
+            play_reverse_test(&mut puzzle, acceptable_dic);
+        }
+        if game_config.keeps_history() {
+            record_reverse(game_config, game, &puzzle)?;
+        }
+        if !game_config.word.is_empty() {
+            break;
+        }
+        if is_tty {
+            println!();
+            println!("Type in 'Y' to continue...");
+            println!("Type in 'N' to quit...");
+        }
+        let mut ans = String::new();
+        io::stdin().read_line(&mut ans).unwrap();
+        if ans.trim() != "Y" {
+            break;
+        }
+        day += 1;
+    }
+    Ok(())
+}
+
+// In "Reverse Mode", to read an entry until it is a valid word.
+fn ask_for_entry(puzzle: &Puzzle, acceptable_dic: &[String], is_tty: bool) -> String {
+    loop {
+        let mut entry = String::new();
+        if std::io::stdin().read_line(&mut entry).unwrap() == 0 {
+            panic!("No guess is given!");
+        }
+        let entry = sanitize(&entry);
+        match puzzle.check(&entry, acceptable_dic) {
+            Ok(()) => return entry,
+            Err(_) if !is_tty => println!("INVALID"),
+            Err(GuessError::NotInDictionary) => {
+                println!("{} {} {}", "INVALID! The word".red(), entry.to_uppercase().red(), "isn't in the Wordle dictionary.".red())
+            }
+            Err(e) => println!("{}", format!("INVALID! {}.", e).red()),
+        }
+    }
+}
+
+// In "Reverse Mode", to display the rows to give, with the words giving them so far.
+fn display_rows(puzzle: &Puzzle) {
+    for (row_number, row) in puzzle.rows().iter().enumerate() {
+        print!("{}: ", row_number + 1);
+        match puzzle.solved().get(row_number) {
+            Some(word) => {
+                for (c, state) in word.chars().zip(row.tiles()) {
+                    print!("{}", paint(c, Some(*state)));
+                }
+            }
+            None => {
+                for state in row.tiles() {
+                    print!("{}", paint('■', Some(*state)));
+                }
+            }
+        }
+        println!();
+    }
+}
+
+// In "Reverse Mode" and "Interactive Mode", play a puzzle.
+fn play_reverse(puzzle: &mut Puzzle, acceptable_dic: &[String]) {
+    println!("The answer is {}. Enter a word giving each of these rows against it:", puzzle.answer().to_uppercase());
+    display_rows(puzzle);
+    while !puzzle.is_over() {
+        let tries_left = match puzzle.tries_left() {
+            Some(tries_left) => format!("{} tries left", tries_left),
+            None => "unlimited tries".to_string(),
+        };
+        println!("{}", format!("Enter a word giving row {} and press ENTER: {}", puzzle.solved().len() + 1, tries_left).cyan());
+        let entry = ask_for_entry(puzzle, acceptable_dic, true);
+        let n_solved = puzzle.solved().len();
+        let feedback = puzzle.play(&entry).clone();
+        if puzzle.solved().len() > n_solved {
+            display_rows(puzzle);
+        } else {
+            for (c, state) in entry.chars().zip(feedback.tiles()) {
+                print!("{}", paint(c, Some(*state)));
+            }
+            println!(" {}", "gives another row!".red());
+        }
+    }
+    if puzzle.is_won() {
+        println!("CORRECT! You gave all {} rows in {} tries.", puzzle.rows().len(), puzzle.entries().len());
+    } else {
+        let row = puzzle.target().unwrap();
+        let solution = &reverse::solutions(puzzle.answer(), row, acceptable_dic)[0];
+        println!("{}", format!("SHAME! You ran out of tries! {} gives row {}", solution.to_uppercase(), puzzle.solved().len() + 1).bright_red());
+    }
+}
+
+// In "Reverse Mode" and "Test Mode", play a puzzle: print the rows to give, then the row given by each entry.
+fn play_reverse_test(puzzle: &mut Puzzle, acceptable_dic: &[String]) {
+    let rows: Vec<String> = puzzle.rows().iter().map(Feedback::to_string).collect();
+    println!("{}", rows.join(" "));
+    while !puzzle.is_over() {
+        let entry = ask_for_entry(puzzle, acceptable_dic, false);
+        println!("{}", puzzle.play(&entry));
+    }
+    if puzzle.is_won() {
+        println!("CORRECT {}", puzzle.entries().len());
+    } else {
+        let solution = &reverse::solutions(puzzle.answer(), puzzle.target().unwrap(), acceptable_dic)[0];
+        println!("{}", format!("FAILED {}", solution.to_uppercase()).bright_red().trim());
+    }
+}
+
+// With the subcommand "solve", print the best next guesses as JSON.
+fn execute_solve(sub_matches: &clap::ArgMatches, game: &WordleGame, final_dic: &[String], acceptable_dic: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let history = solver::parse_history(sub_matches.value_of("history").unwrap_or(""))?;
+    if let Some((guess, _)) = history.iter().find(|(guess, _)| guess.len() != game.length) {
+        return Err(format!("The guess {} must be {} letters!", guess.to_uppercase(), game.length).into());
+    }
+    let top = match sub_matches.value_of("top") {
+        Some(top) => top.trim().parse()?,
+        None => TOP_GUESSES,
+    };
+    let report = solver::solve(final_dic, acceptable_dic, &history, game.difficulty, top);
+    if report.remaining == 0 {
+        return Err("No word in the answer list matches the history!".into());
+    }
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+// With the subcommand "review", replay the games of the state file given by "-S",
+// or only the game given by "--game", counted from 1. Games played with other words are skipped,
+// since their possible answers cannot be counted again.
+fn execute_review(sub_matches: &clap::ArgMatches, game: &WordleGame, game_config: &Configuration, final_dic: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    if !game_config.keeps_history() {
+        return Err("No state file is given by --state or --db!".into());
+    }
+    let game_json = load_history(game_config, STATE_VERSION);
+    let numbers: Vec<usize> = match sub_matches.value_of("game") {
+        Some(number) => {
+            let number: usize = number.trim().parse()?;
+            if number == 0 || number > game_json.games.len() {
+                return Err(format!("There is no game {} in the state file!", number).into());
+            }
+            if !game.played_with_words(&game_json.games[number - 1]) {
+                return Err(format!("Game {} was played with other words! Give the same --length, --final-set and --acceptable-set.", number).into());
+            }
+            vec![number]
+        }
+        None => (1..=game_json.games.len()).collect(),
+    };
+    let is_tty = atty::is(atty::Stream::Stdout);
+    for number in numbers {
+        let single_game = &game_json.games[number - 1];
+        if game.played_with_words(single_game) {
+            review_game(number, single_game, final_dic, is_tty);
+        } else if is_tty {
+            println!("{}", format!("Game {} was played with other words, skipped.", number).yellow());
+        } else {
+            println!("GAME {} SKIPPED", number);
+        }
+    }
+    Ok(())
+}
+
+// To replay a saved game with the true colours of every row, the state of all letters
+// and the number of possible answers of every board after each guess.
+// In "Test Mode", a row prints the guess, the pattern of each board (dashes once it is over),
+// the state of all letters and the number of possible answers of each board.
+fn review_game(number: usize, single_game: &Game, final_dic: &[String], is_tty: bool) {
+    if let Some(puzzle) = single_game.replay_reverse() {
+        review_reverse(number, &puzzle, is_tty);
+        return;
+    }
+    let answers: Vec<String> = if single_game.answers.is_empty() {
+        vec![single_game.answer.to_lowercase()]
+    } else {
+        single_game.answers.iter().map(|answer| answer.to_lowercase()).collect()
+    };
+    let mut boards: Vec<Round> = if single_game.xordle && answers.len() == 2 {
+        vec![Round::xordle(&answers[0], &answers[1], Difficulty::Normal, UNLIMITED_TRIES)]
+    } else {
+        answers.iter().map(|answer| Round::new(answer, Difficulty::Normal, UNLIMITED_TRIES)).collect()
+    };
+    let pairs = if single_game.xordle { xordle::disjoint_pairs(final_dic) } else { Vec::new() };
+    let outcome = match single_game.survived {
+        Some(survived) => format!("survived {} guesses", survived),
+        None if single_game.is_win() => format!("won in {} tries", single_game.guesses.len()),
+        None => "lost".to_string(),
+    };
+    let answers: Vec<String> = answers.iter().map(|answer| answer.to_uppercase()).collect();
+    if is_tty {
+        println!("{}", format!("Game {}: {} ({})", number, answers.join(", "), outcome).cyan());
+    } else {
+        println!("GAME {} {}", number, answers.join(" "));
+    }
+
+    for (row, guess) in single_game.guesses.iter().enumerate() {
+        let guess = guess.to_lowercase();
+        let mut patterns: Vec<Option<Feedback>> = Vec::new();
+        let mut remaining: Vec<Option<usize>> = Vec::new();
+        let mut keyboard = KeyboardState::new();
+        for board in boards.iter_mut() {
+            if board.is_over() {
+                patterns.push(None);
+                remaining.push(None);
+            } else {
+                patterns.push(Some(board.play(&guess).clone()));
+                remaining.push(Some(if single_game.xordle {
+                    xordle::filter_pairs(&pairs, board.history()).len()
+                } else {
+                    solver::filter_candidates(final_dic, board.history()).len()
+                }));
+            }
+            keyboard.merge(board.keyboard());
+        }
+        let remaining: Vec<String> = remaining.iter().map(|left| left.map_or("-".to_string(), |left| left.to_string())).collect();
+        if is_tty {
+            print!("{}: ", row + 1);
+            for pattern in &patterns {
+                match pattern {
+                    Some(pattern) => {
+                        for (c, state) in guess.chars().zip(pattern.tiles()) {
+                            print!("{}", paint(c, Some(*state)));
+                        }
+                    }
+                    None => print!("{}", " ".repeat(guess.len())),
+                }
+                print!("  ");
+            }
+            for c in CHAR_LIST {
+                print!("{}", paint(*c, keyboard.get(*c)));
+            }
+            print!("  {} left", remaining.join(" "));
+            if row < single_game.prefilled.unwrap_or(0) {
+                print!(" (prefilled)");
+            }
+            println!();
+        } else {
+            let patterns: Vec<String> = patterns.iter()
+                .map(|pattern| pattern.as_ref().map_or("-".repeat(guess.len()), Feedback::to_string))
+                .collect();
+            println!("{} {} {} {}", guess.to_uppercase(), patterns.join(" "), keyboard, remaining.join(" "));
+        }
+    }
+    if !is_tty {
+        match single_game.survived {
+            Some(survived) => println!("SURVIVED {}", survived),
+            None if single_game.is_win() => println!("CORRECT {}", single_game.guesses.len()),
+            None => println!("FAILED"),
+        }
+    }
+}
+
+// In "Reverse Mode", to print a saved puzzle: the rows to give, then the row given by each entry.
+fn review_reverse(number: usize, puzzle: &Puzzle, is_tty: bool) {
+    let answer = puzzle.answer().to_uppercase();
+    if is_tty {
+        let outcome = if puzzle.is_won() {
+            format!("gave all {} rows in {} tries", puzzle.rows().len(), puzzle.entries().len())
+        } else {
+            "lost".to_string()
+        };
+        println!("{}", format!("Game {}: {} ({})", number, answer, outcome).cyan());
+        display_rows(puzzle);
+    } else {
+        let rows: Vec<String> = puzzle.rows().iter().map(Feedback::to_string).collect();
+        println!("GAME {} {}", number, answer);
+        println!("{}", rows.join(" "));
+        for (entry, feedback) in puzzle.entries() {
+            println!("{} {}", entry.to_uppercase(), feedback);
+        }
+        if puzzle.is_won() {
+            println!("CORRECT {}", puzzle.entries().len());
+        } else {
+            println!("FAILED");
+        }
+    }
+}
+
+// With the subcommand "players", compare the statistics of every player of the state file or database,
+// counting the games as "--stats" does. Games without a player are counted apart, on a last line as "-".
+fn execute_players(game: &WordleGame, game_config: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+    if !game_config.keeps_history() {
+        return Err("No state file is given by --state or --db!".into());
+    }
+    let game_json = load_history(game_config, STATE_VERSION);
+    let new_profile = || {
+        let mut profile = WordleGame::new();
+        profile.max_tries = game.max_tries;
+        profile
+    };
+    let mut profiles: BTreeMap<String, WordleGame> = BTreeMap::new();
+    let mut no_player: Option<WordleGame> = None;
+    for single_game in game_json.games.iter().filter(|single_game| game.counts_source(single_game)) {
+        let profile = match &single_game.player {
+            Some(player) => profiles.entry(player.clone()).or_insert_with(new_profile),
+            None => no_player.get_or_insert_with(new_profile),
+        };
+        profile.add_history(single_game);
+    }
+    let is_tty = atty::is(atty::Stream::Stdout);
+    if is_tty {
+        println!("{:<16} {:>6} {:>6} {:>6} {:>6}  FAVOURITE WORDS", "PLAYER", "GAMES", "WINS", "LOSSES", "TRIES");
+    }
+    let rows = profiles.iter().map(|(player, profile)| (Some(player.as_str()), profile)).chain(no_player.iter().map(|profile| (None, profile)));
+    for (player, profile) in rows {
+        let games = profile.win + profile.lose + profile.anti_scores.len() as u64;
+        let top_words: Vec<String> = profile.top_words().iter()
+            .map(|(word, cnt)| format!("{} {}", word.to_uppercase(), cnt))
+            .collect();
+        if is_tty {
+            println!("{:<16} {:>6} {:>6} {:>6} {:>6.2}  {}", player.unwrap_or("-"), games, profile.win, profile.lose, profile.average_tries(), top_words.join(", "));
+        } else {
+            let mut line = vec![player.unwrap_or("-").to_string(), games.to_string(), profile.win.to_string(), profile.lose.to_string(), format!("{:.2}", profile.average_tries())];
+            line.extend(top_words);
+            println!("{}", line.join(" "));
+        }
+    }
+    Ok(())
+}
+
+// To copy the games of state files into the database given by "--db", skipping the games already there
+// so that a state file can be imported again.
+// Older state files are migrated first, and the high scores of "Survival Mode" are merged.
+#[cfg(feature = "sqlite")]
+fn execute_import(sub_matches: &clap::ArgMatches, game_config: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+    if game_config.db.is_empty() {
+        return Err("No database is given by --db!".into());
+    }
+    let is_tty = atty::is(atty::Stream::Stdout);
+    for path in sub_matches.values_of("files").unwrap() {
+        let game_json = load_state(path, STATE_VERSION);
+        let imported = import_db(&game_config.db, &game_json.games, game_json.total_rounds)?;
+        if !game_json.survival.is_empty() {
+            let mut runs = load_survival_db(&game_config.db)?;
+            // The runs already in the database are not added again either.
+            let mut known = vec![false; runs.len()];
+            for run in game_json.survival {
+                let same = (0..known.len()).find(|&i| {
+                    !known[i] && runs[i].rule == run.rule && runs[i].score == run.score && runs[i].max_tries == run.max_tries
+                });
+                match same {
+                    Some(i) => known[i] = true,
+                    None => runs.push(run),
+                }
+            }
+            keep_high_scores(&mut runs);
+            save_survival_db(&game_config.db, &runs)?;
+        }
+        if is_tty {
+            println!("Imported {} games from {}, skipping {} already in the database.", imported, path, game_json.games.len() - imported);
+        } else {
+            println!("IMPORTED {}", imported);
+        }
+    }
+    Ok(())
+}
+
+// To print the games, wins, losses and average tries of every player or date in the database given by "--db",
+// or only of the one given.
+#[cfg(feature = "sqlite")]
+fn execute_stats(sub_matches: &clap::ArgMatches, game_config: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+    if game_config.db.is_empty() {
+        return Err("No database is given by --db!".into());
+    }
+    let grouping = match sub_matches.value_of("by").unwrap() {
+        "player" => Grouping::Player,
+        "date" => Grouping::Date,
+        by => return Err(format!("Cannot group the statistics by {}!", by).into()),
+    };
+    let all_stats = open_db(&game_config.db)?.stats(grouping, sub_matches.value_of("key"))?;
+    let is_tty = atty::is(atty::Stream::Stdout);
+    if is_tty {
+        println!("{:<16} {:>6} {:>6} {:>6} {:>6}", sub_matches.value_of("by").unwrap().to_uppercase(), "GAMES", "WINS", "LOSSES", "TRIES");
+    }
+    for stats in all_stats {
+        let key = stats.key.as_deref().unwrap_or("-");
+        if is_tty {
+            println!("{:<16} {:>6} {:>6} {:>6} {:>6.2}", key, stats.games, stats.wins, stats.losses, stats.average_tries());
+        } else {
+            println!("{} {} {} {} {:.2}", key, stats.games, stats.wins, stats.losses, stats.average_tries());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn execute_import(_sub_matches: &clap::ArgMatches, _game_config: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+    Err(NO_SQLITE.into())
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn execute_stats(_sub_matches: &clap::ArgMatches, _game_config: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+    Err(NO_SQLITE.into())
+}
+
+// With the subcommand "create", print the code of a puzzle with the given answer and the current settings.
+fn execute_create(sub_matches: &clap::ArgMatches, game: &WordleGame, acceptable_dic: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let word = sanitize(sub_matches.value_of("word").unwrap());
+    if word.len() != game.length {
+        return Err(format!("The word {} must be {} letters!", word.to_uppercase(), game.length).into());
+    }
+    if !acceptable_dic.iter().any(|acceptable| acceptable.trim() == word) {
+        return Err(format!("The word {} isn't in the Wordle dictionary!", word.to_uppercase()).into());
+    }
+    println!("{}", PuzzleCode::new(&word, game.difficulty, game.max_tries).encode()?);
+    Ok(())
+}
+
+// With the subcommand "tree", build or load a decision tree and print its statistics.
+fn execute_tree(sub_matches: &clap::ArgMatches, game: &WordleGame, final_dic: &[String], acceptable_dic: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let export: TreeExport = if let Some(path) = sub_matches.value_of("load") {
+        serde_json::from_reader(BufReader::new(File::open(path)?))?
+    } else {
+        let opener = match sub_matches.value_of("opener") {
+            Some(opener) => sanitize(opener),
+            None => solver::rank_guesses(acceptable_dic, final_dic)[0].word.clone(),
+        };
+        if !acceptable_dic.contains(&opener) {
+            return Err(format!("The word {} isn't in the Wordle dictionary!", opener.to_uppercase()).into());
+        }
+        let tree = DecisionTree::build(&opener, final_dic, acceptable_dic, game.difficulty);
+        TreeExport::new(tree, final_dic, game.difficulty)?
+    };
+    println!("{} {} {:.2} {}", export.tree.guess.to_uppercase(), export.words, export.average, export.worst);
+
+    // Print the guess played and the guess of the tree for each step, then the next guess of the tree.
+    if let Some(history) = sub_matches.value_of("history") {
+        let history = solver::parse_history(history)?;
+        let recommendations = export.tree.recommendations(&history);
+        for ((guess, _), recommendation) in history.iter().zip(&recommendations) {
+            println!("{} {}", guess.to_uppercase(), recommendation.to_uppercase());
+        }
+        if let Some(next) = recommendations.get(history.len()) {
+            println!("NEXT {}", next.to_uppercase());
+        }
+    }
+    if let Some(path) = sub_matches.value_of("output") {
+        let buf_writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(buf_writer, &export)?;
+    }
+    Ok(())
+}
+
+// With the subcommand "bench", play a strategy against every answer and print its statistics.
+fn execute_bench(sub_matches: &clap::ArgMatches, game: &WordleGame, game_config: &Configuration, final_dic: &[String], acceptable_dic: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut strategy: Box<dyn Strategy> = match sub_matches.value_of("strategy").unwrap_or("entropy").trim() {
+        "entropy" => {
+            let opener = sub_matches.value_of("opener").map(sanitize);
+            if let Some(opener) = &opener {
+                if !acceptable_dic.contains(opener) {
+                    return Err(format!("The word {} isn't in the Wordle dictionary!", opener.to_uppercase()).into());
+                }
+            }
+            Box::new(TreeStrategy::entropy(opener.as_deref(), final_dic, acceptable_dic, game.difficulty))
+        }
+        "first" => Box::new(FirstStrategy::new(final_dic)),
+        "random" => Box::new(RandomStrategy::new(final_dic, game_config.seed)),
+        "tree" => {
+            let path = sub_matches.value_of("load").ok_or("The tree strategy needs a tree file given by --load!")?;
+            let export: TreeExport = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+            Box::new(TreeStrategy::new(export.tree))
+        }
+        other => return Err(format!("Unknown strategy {}, expected one of: {}!", other, bench::STRATEGIES.join(", ")).into()),
+    };
+    let report = bench::run(strategy.as_mut(), final_dic, acceptable_dic, game.difficulty, game.max_tries);
+
+    // Print the histogram up to the limit of tries, or up to the longest win without a limit.
+    println!("GAMES {}", report.games);
+    println!("WIN RATE {:.2}%", report.win_rate() * 100.0);
+    println!("MEAN {:.2}", report.mean_guesses());
+    let longest = report.histogram.keys().next_back().copied().unwrap_or(0);
+    let upper = if game.max_tries == UNLIMITED_TRIES { longest } else { game.max_tries };
+    for n_tries in 1..=upper {
+        println!("{} {}", n_tries, report.histogram.get(&n_tries).unwrap_or(&0));
+    }
+    let failed: Vec<String> = report.failed.iter().map(|word| word.to_uppercase()).collect();
+    println!("FAILED {}", report.failed.len());
+    if !failed.is_empty() {
+        println!("{}", failed.join(" "));
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Initialization of Wordle Game, Command Line Parameters and Configuration Parameters.
+    let mut game = WordleGame::new();
+    let yml = clap::load_yaml!("yaml.yml");
+    let matches = App::from_yaml(yml).get_matches();
+    let mut game_config = Configuration::new();
+
+    // Initialization of available words.
+    let mut final_dictionary: Vec<String> = builtin_words::FINAL.iter().map(|&x| x.to_string()).collect();
+    let mut acceptable_dictionary: Vec<String> = builtin_words::ACCEPTABLE.iter().map(|&x| x.to_string()).collect();
+    
+    // Deal with parameter "-c".
+    if let Some(path) = matches.value_of("load_configuration"){
+        if let Err(e) = File::open(path) { panic!("{}", e); }
+        let filename = File::open(path).unwrap();
+        let config: Configuration = serde_json::from_reader(filename).unwrap();
+        game_config = config;
     }
 
     // Update configuration file "game_config".
     if let Some(word) = matches.value_of("input_word"){ game_config.word = word.to_string(); }
     if matches.occurrences_of("random_word") == 1 { game_config.random = true; }
     if matches.occurrences_of("difficult_word") == 1 { game_config.difficult = true; }
+    if matches.occurrences_of("ultra_difficult_word") == 1 { game_config.ultra_hard = true; }
+    if matches.occurrences_of("absurdle_word") == 1 { game_config.absurdle = true; }
+    if matches.occurrences_of("fibble_word") == 1 { game_config.fibble = true; }
+    if matches.occurrences_of("xordle_word") == 1 { game_config.xordle = true; }
+    if matches.occurrences_of("anti_word") == 1 { game_config.anti = true; }
+    if matches.occurrences_of("reverse_word") == 1 { game_config.reverse = true; }
     if matches.occurrences_of("statistical_word") == 1 { game_config.stats = true; }
     if let Some(path_final) = matches.value_of("set_final_words") {
         if let Some(path_acceptable) = matches.value_of("set_acceptable_words") {
@@ -689,6 +1961,45 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     if let Some(rand_day) = matches.value_of("rand_day") { game_config.day = rand_day.trim().parse().unwrap(); }
     if let Some(rand_seed) = matches.value_of("rand_seed") { game_config.seed = rand_seed.trim().parse().unwrap(); }
     if let Some(states) = matches.value_of("load_state") { game_config.state = states.to_string(); }
+    if let Some(db) = matches.value_of("db") { game_config.db = db.to_string(); }
+    if let Some(player) = matches.value_of("player") { game_config.player = player.to_string(); }
+    if let Some(length) = matches.value_of("word_length") { game_config.length = length.trim().parse().unwrap(); }
+    if let Some(max_tries) = matches.value_of("max_tries") { game_config.max_tries = parse_max_tries(max_tries); }
+    if let Some(boards) = matches.value_of("boards") { game_config.boards = boards.trim().parse().unwrap(); }
+    if let Some(seconds) = matches.value_of("timed") { game_config.timed = seconds.trim().parse().unwrap(); }
+    if let Some(seconds) = matches.value_of("guess_time") { game_config.guess_time = seconds.trim().parse().unwrap(); }
+    if let Some(rule) = matches.value_of("survival") { game_config.survival = rule.trim().to_lowercase(); }
+    if let Some(words) = matches.value_of("prefill") { game_config.prefill = words.to_string(); }
+    if let Some(rows) = matches.value_of("rows") { game_config.rows = rows.trim().to_string(); }
+    if matches.occurrences_of("daily") == 1 { game_config.daily = true; }
+    if let Some(date) = matches.value_of("date") { game_config.date = date.trim().to_string(); }
+    if let Some(epoch) = matches.value_of("epoch") { game_config.epoch = epoch.trim().to_string(); }
+    if let Some(source) = matches.value_of("stats_source") { game_config.stats_source = source.trim().to_lowercase(); }
+    if let Some(version) = matches.value_of("state_format_version") { game_config.state_format_version = version.trim().parse().unwrap(); }
+    if game_config.state_format_version > STATE_VERSION {
+        panic!("State format version {} is not supported, the latest is {}!", game_config.state_format_version, STATE_VERSION);
+    }
+    if !game_config.db.is_empty() && !game_config.state.is_empty() {
+        panic!("Contradictory parameters!")
+    }
+    #[cfg(not(feature = "sqlite"))]
+    if !game_config.db.is_empty() {
+        panic!("{}", NO_SQLITE)
+    }
+
+    // Deal with subcommand "play": the puzzle of the code is played as if given by "-w", with its own settings.
+    let code = matches.subcommand_matches("play").and_then(|sub_matches| sub_matches.value_of("code"));
+    if let Some(code) = code {
+        if !game_config.word.is_empty() {
+            panic!("Contradictory parameters!")
+        }
+        let puzzle = PuzzleCode::decode(code)?;
+        game_config.word = puzzle.answer;
+        game_config.length = game_config.word.len();
+        game_config.max_tries = puzzle.max_tries;
+        game_config.difficult = puzzle.difficulty != Difficulty::Normal;
+        game_config.ultra_hard = puzzle.difficulty == Difficulty::UltraHard;
+    }
 
     // Deal with parameter "-a", "-f".
     if !game_config.final_set.is_empty() && !game_config.acceptable_set.is_empty() {
@@ -696,58 +2007,282 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let mut tmp_acceptable_dic: Vec<String> = Vec::new();
         let set_final = load_data(&game_config.final_set, &mut tmp_final_dic);
         let set_acceptable = load_data(&game_config.acceptable_set, &mut tmp_acceptable_dic);
-        assert_eq!(set_final.is_subset(&set_acceptable), true);
-        final_dictionary = tmp_final_dic.clone();
-        acceptable_dictionary = tmp_acceptable_dic.clone();
-    }
-
-    // Deal with parameter "-S".
-    if !game_config.state.is_empty(){
-        if let Err(e) = File::open(game_config.state.clone()) { panic!("{}", e); }
-        let filename = File::open(game_config.state.clone()).unwrap();
-        let game_json: Games = serde_json::from_reader(filename).unwrap();
-        for single_game in &game_json.games {
-            for guess in &single_game.guesses {
-                *game.used_words.entry(guess.to_lowercase().clone()).or_insert(0) += 1;
-            }
-            if single_game.answer == single_game.guesses[single_game.guesses.len() - 1] {
-                game.win += 1;
-            } else {
-                game.lose += 1;
+        assert!(set_final.is_subset(&set_acceptable));
+        final_dictionary = tmp_final_dic;
+        acceptable_dictionary = tmp_acceptable_dic;
+    }
+
+    // Deal with parameter "-l".
+    if game_config.length < MIN_LENGTH || game_config.length > MAX_LENGTH {
+        panic!("The word length must be between {} and {}!", MIN_LENGTH, MAX_LENGTH);
+    }
+    final_dictionary.retain(|word| word.trim().len() == game_config.length);
+    acceptable_dictionary.retain(|word| word.trim().len() == game_config.length);
+    if final_dictionary.is_empty() {
+        panic!("There are no {}-letter words in the word list!", game_config.length);
+    }
+    if !game_config.word.is_empty() && parse_words(&game_config.word).iter().any(|word| word.len() != game_config.length) {
+        panic!("Contradictory parameters!")
+    }
+    if !game_config.prefill.is_empty() && parse_words(&game_config.prefill).iter().any(|word| word.len() != game_config.length) {
+        panic!("Contradictory parameters!")
+    }
+    if code.is_some() && !acceptable_dictionary.iter().any(|word| word.trim() == game_config.word) {
+        return Err("The puzzle code doesn't match the word list!".into());
+    }
+    game.length = game_config.length;
+    game.max_tries = game_config.max_tries;
+    game.word_list = word_list_identity(&final_dictionary, &acceptable_dictionary, !game_config.final_set.is_empty());
+
+    // Deal with parameter "-b".
+    if game_config.boards < 1 || game_config.boards > MAX_BOARDS.min(final_dictionary.len()) {
+        panic!("The number of boards must be between 1 and {}!", MAX_BOARDS.min(final_dictionary.len()));
+    }
+    if game_config.boards > 1 && game_config.absurdle {
+        panic!("Contradictory parameters!")
+    }
+    if !game_config.word.is_empty() && parse_words(&game_config.word).len() != game_config.answers_per_game() {
+        panic!("Contradictory parameters!")
+    }
+
+    // Deal with parameter "-D", "-U".
+    if game_config.ultra_hard {
+        game_config.difficult = true;
+        game.difficulty = Difficulty::UltraHard;
+    } else if game_config.difficult {
+        game.difficulty = Difficulty::Hard;
+    }
+
+    // Deal with parameter "-F".
+    if game_config.fibble {
+        if game_config.difficult || game_config.absurdle || game_config.boards > 1 {
+            panic!("Contradictory parameters!")
+        }
+        game.liar = Some(StdRng::seed_from_u64(game_config.seed));
+    }
+
+    // Deal with parameter "-X".
+    if game_config.xordle {
+        if game_config.difficult || game_config.absurdle || game_config.fibble || game_config.boards > 1 {
+            panic!("Contradictory parameters!")
+        }
+        if !game_config.word.is_empty() {
+            let words = parse_words(&game_config.word);
+            if xordle::disjoint_pairs(&words).is_empty() {
+                panic!("The two answers must not have any letter in common!");
             }
-            game.tries += single_game.guesses.len() as u64;
         }
+        game.xordle = true;
     }
 
-    // Game Start: Interactive Mode.
+    // Deal with parameter "--anti": the rules are always the strictest ones, and there is no limit of tries.
+    if game_config.anti {
+        if game_config.absurdle || game_config.fibble || game_config.xordle || game_config.boards > 1 || !game_config.survival.is_empty() {
+            panic!("Contradictory parameters!")
+        }
+        game.anti = true;
+        game.difficulty = Difficulty::UltraHard;
+        game.max_tries = UNLIMITED_TRIES;
+    }
+
+    // Deal with parameter "--timed", "--guess-time", where 0 means no limit.
+    if game_config.timed > 0 {
+        game.time_limit = Some(Duration::from_secs(game_config.timed));
+    }
+    if game_config.guess_time > 0 {
+        game.guess_time_limit = Some(Duration::from_secs(game_config.guess_time));
+    }
+
+    // Deal with parameter "--survival".
+    if !game_config.survival.is_empty() {
+        if game_config.survival != "carry" && game_config.survival != "shrink" {
+            panic!("The survival rule must be \"carry\" or \"shrink\"!");
+        }
+        if !game_config.word.is_empty() || !game_config.prefill.is_empty() || game_config.absurdle || game_config.max_tries == UNLIMITED_TRIES {
+            panic!("Contradictory parameters!")
+        }
+    }
+
+    // Deal with parameter "--daily", "--date", "--epoch": the puzzle of a date is found by counting the days from the epoch.
+    // A date given by "--date" implies "--daily", which plays today's puzzle otherwise.
+    let mut daily_number: Option<i64> = None;
+    if game_config.daily || !game_config.date.is_empty() {
+        if !game_config.word.is_empty() || game_config.random || game_config.absurdle || game_config.reverse || !game_config.survival.is_empty()
+            || game_config.day != default_day() {
+            panic!("Contradictory parameters!")
+        }
+        let date = if game_config.date.is_empty() { daily::day_of(now()) } else { daily::parse_date(&game_config.date)? };
+        let epoch = daily::parse_date(&game_config.epoch)?;
+        daily_number = Some(daily::puzzle_number(date, epoch));
+        game.date = Some(daily::format_date(date));
+    }
+
+    // Deal with parameter "--source", and tag the games of this run with the way their answers are chosen.
+    if !game_config.stats_source.is_empty() {
+        if !SOURCES.contains(&game_config.stats_source.as_str()) {
+            panic!("The source must be one of: {}!", SOURCES.join(", "));
+        }
+        game.stats_source = Some(game_config.stats_source.clone());
+    }
+    game.source = if game_config.reverse {
+        "reverse"
+    } else if code.is_some() {
+        "code"
+    } else if daily_number.is_some() {
+        "daily"
+    } else if !game_config.word.is_empty() {
+        "word"
+    } else if game_config.random || !game_config.survival.is_empty() {
+        "random"
+    } else if game_config.absurdle {
+        "absurdle"
+    } else {
+        "manual"
+    };
+
+    // Deal with parameter "--reverse", "--rows".
+    if game_config.reverse {
+        if game_config.absurdle || game_config.fibble || game_config.xordle || game_config.anti || game_config.boards > 1
+            || !game_config.survival.is_empty() || !game_config.prefill.is_empty() || game.is_timed()
+            || (!game_config.word.is_empty() && game_config.random) {
+            panic!("Contradictory parameters!")
+        }
+        if !game_config.rows.is_empty() {
+            match parse_rows(&game_config.rows) {
+                Some(rows) if rows.iter().all(|row| row.len() == game_config.length) => {}
+                Some(_) => panic!("Contradictory parameters!"),
+                None => panic!("The rows must be patterns of G, Y and R, separated by commas!"),
+            }
+        }
+    }
+
+    // Deal with subcommand "solve".
+    if let Some(sub_matches) = matches.subcommand_matches("solve") {
+        return execute_solve(sub_matches, &game, &final_dictionary, &acceptable_dictionary);
+    }
+
+    // Deal with subcommand "review".
+    if let Some(sub_matches) = matches.subcommand_matches("review") {
+        return execute_review(sub_matches, &game, &game_config, &final_dictionary);
+    }
+
+    // Deal with subcommands "import" and "stats".
+    if let Some(sub_matches) = matches.subcommand_matches("import") {
+        return execute_import(sub_matches, &game_config);
+    }
+    if let Some(sub_matches) = matches.subcommand_matches("stats") {
+        return execute_stats(sub_matches, &game_config);
+    }
+
+    // Deal with subcommand "players".
+    if matches.subcommand_matches("players").is_some() {
+        return execute_players(&game, &game_config);
+    }
+
+    // Deal with subcommand "create".
+    if let Some(sub_matches) = matches.subcommand_matches("create") {
+        return execute_create(sub_matches, &game, &acceptable_dictionary);
+    }
+
+    // Deal with subcommand "tree".
+    if let Some(sub_matches) = matches.subcommand_matches("tree") {
+        return execute_tree(sub_matches, &game, &final_dictionary, &acceptable_dictionary);
+    }
+
+    // Deal with subcommand "bench".
+    if let Some(sub_matches) = matches.subcommand_matches("bench") {
+        return execute_bench(sub_matches, &game, &game_config, &final_dictionary, &acceptable_dictionary);
+    }
+
+    // Deal with parameter "--player": in "Interactive Mode", the name entered is the player otherwise.
     let is_tty = atty::is(atty::Stream::Stdout);
+    if matches.is_present("player") || !game_config.player.is_empty() {
+        if !is_player_name(&game_config.player) {
+            return Err(format!("Invalid player name \"{}\"! A name is a single word other than \"-\".", game_config.player).into());
+        }
+        game.player = Some(game_config.player.clone());
+    }
     if is_tty {
-        print!("{}", console::style("Please enter your name: ").bold().red());
-        io::stdout().flush().unwrap();
-        let mut line = String::new();
-        io::stdin().read_line(&mut line)?;
-        println!("Welcome to Wordle, {}!", line.trim());
+        while game.player.is_none() {
+            print!("{}", console::style("Please enter your name: ").bold().red());
+            io::stdout().flush().unwrap();
+            let mut line = String::new();
+            io::stdin().read_line(&mut line)?;
+            let name = line.trim();
+            if name.is_empty() {
+                break;
+            }
+            if is_player_name(name) {
+                game.player = Some(name.to_string());
+            } else {
+                println!("{}", "INVALID! A name is a single word other than \"-\".".red());
+            }
+        }
+        println!("Welcome to Wordle, {}!", game.player.as_deref().unwrap_or_default());
         println!();
+    }
 
-        if !game_config.word.is_empty() {
-            if game_config.random == true || game_config.seed != DEFAULT_SEED || !game_config.state.is_empty() {
+    // Deal with parameter "-S" or "--db".
+    // From a database, only the games of the player and source counted are read.
+    if game_config.keeps_history() {
+        let history = if game_config.db.is_empty() {
+            load_state(&game_config.state, game_config.state_format_version).games
+        } else {
+            load_db_games(&game_config.db, game.player.as_deref(), game.stats_source.as_deref())
+        };
+        if let Some(date) = &game.date {
+            let played = if game_config.db.is_empty() {
+                history.iter().any(|single_game| single_game.date.as_ref() == Some(date) && (game.player.is_none() || single_game.player == game.player))
+            } else {
+                db_has_daily(&game_config.db, date, game.player.as_deref())?
+            };
+            if played {
+                return Err(format!("The daily puzzle of {} has already been played!", date).into());
+            }
+        }
+        for single_game in &history {
+            if game.counts_history(single_game) {
+                game.add_history(single_game);
+            }
+        }
+    }
+
+    // Game Start: Interactive Mode.
+    if is_tty {
+        if !game_config.survival.is_empty() {
+            execute_survival(&mut game, &game_config, &final_dictionary, &acceptable_dictionary, is_tty)?;
+        }
+        else if game_config.reverse {
+            execute_reverse(&mut game, &game_config, &final_dictionary, &acceptable_dictionary, is_tty)?;
+        }
+        else if let Some(number) = daily_number {
+            execute_daily(&mut game, &game_config, number, &final_dictionary, &acceptable_dictionary, is_tty)?;
+        }
+        else if !game_config.word.is_empty() {
+            if game_config.random || game_config.absurdle || game_config.seed != DEFAULT_SEED {
                 panic!("Contradictory parameters!")
             }
-            game.word = game_config.word.to_lowercase();
-            game.execute_game(&game_config, &acceptable_dictionary);
+            game.start(&parse_words(&game_config.word));
+            game.execute_game(&game_config, &final_dictionary, &acceptable_dictionary);
+            if game_config.keeps_history() {
+                record_game(&game_config, &game)?;
+            }
         }
-        else if game_config.random == false {   
-            if game_config.seed != DEFAULT_SEED || !game_config.state.is_empty() {
+        else if !game_config.random {   
+            if game_config.seed != DEFAULT_SEED {
                 panic!("Contradictory parameters!")
             }
             loop {  
-                println!("Input a word as the answer of this Wordle Game: ");
-                let mut read_word = String::new();
-                std::io::stdin().read_line(&mut read_word).unwrap();
-                game.word = read_word.clone().to_lowercase();
-                game.update();
-                game.color_initialization();
-                game.execute_game(&game_config, &acceptable_dictionary);
+                if game_config.absurdle {
+                    game.start_absurdle(&final_dictionary);
+                } else {
+                    let words: Vec<String> = (0..game_config.answers_per_game()).map(|_| game.ask_for_answer(is_tty)).collect();
+                    game.start(&words);
+                }
+                game.execute_game(&game_config, &final_dictionary, &acceptable_dictionary);
+                if game_config.keeps_history() {
+                    record_game(&game_config, &game)?;
+                }
                 println!();
                 println!("Type in 'Y' to continue...");
                 println!("Type in 'N' to quit...");
@@ -758,40 +2293,32 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 if ans == "Y" {
                     continue;
                 }
-                else if ans == "N" || ans.len() == 0 {
+                else if ans == "N" || ans.is_empty() {
                     break;
                 }
             }
         }
         else {
+            if game_config.absurdle {
+                panic!("Contradictory parameters!")
+            }
             let mut day: u64 = game_config.day;
             day -= 1;
-            let seed: u64 = game_config.seed;
-            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
-            let mut array = final_dictionary.clone();
-            array.shuffle(&mut rng);
+            let array = shuffle_answers(&final_dictionary, &game_config);
             
             loop {
-                if day > array.len() as u64 - 1 {
-                    day -= array.len() as u64;
-                }
-                game.word = array[day as usize].to_string();
-                game.update();
-                game.color_initialization();
-                game.execute_game(&game_config, &acceptable_dictionary);
+                day %= array.len() as u64;
+                // Each round takes as many consecutive words of the shuffled list as there are boards.
+                let words: Vec<String> = (0..game_config.answers_per_game())
+                    .map(|board| array[(day as usize * game_config.answers_per_game() + board) % array.len()].clone())
+                    .collect();
+                game.seed = Some(game_config.seed);
+                game.day = Some(day + 1);
+                game.start(&words);
+                game.execute_game(&game_config, &final_dictionary, &acceptable_dictionary);
                 
-                if !game_config.state.is_empty() {
-                    if let Err(e) = File::open(&game_config.state) { panic!("{}", e); }
-                    let filename = File::open(&game_config.state).unwrap();
-                    let mut game_json: Games = serde_json::from_reader(filename).unwrap();
-                    game_json.total_rounds += 1;
-                    let mut single_game: Game = Game::new();
-                    single_game.answer = game.word.to_uppercase();
-                    single_game.guesses = game.guesses.iter().map(|x| x.to_uppercase()).collect();
-                    game_json.games.push(single_game);
-                    let file = OpenOptions::new().write(true).create(true).open(&game_config.state)?;
-                    let buf_writer = BufWriter::new(file);
-                    serde_json::to_writer_pretty(buf_writer, &game_json).unwrap();
+                if game_config.keeps_history() {
+                    record_game(&game_config, &game)?;
                 }
                 println!();
                 println!("Type in 'Y' to continue...");
@@ -804,7 +2331,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     day += 1;
                     continue;
                 }
-                else if ans == "N" || ans.len() == 0 {
+                else if ans == "N" || ans.is_empty() {
                     break;
                 }
             }
@@ -813,24 +2340,40 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Game Start: Test Mode.
     if !is_tty {
-        if !game_config.word.is_empty() {
-            if game_config.random == true || game_config.seed != DEFAULT_SEED || !game_config.state.is_empty() {
+        if !game_config.survival.is_empty() {
+            execute_survival(&mut game, &game_config, &final_dictionary, &acceptable_dictionary, is_tty)?;
+        }
+        else if game_config.reverse {
+            execute_reverse(&mut game, &game_config, &final_dictionary, &acceptable_dictionary, is_tty)?;
+        }
+        else if let Some(number) = daily_number {
+            execute_daily(&mut game, &game_config, number, &final_dictionary, &acceptable_dictionary, is_tty)?;
+        }
+        else if !game_config.word.is_empty() {
+            if game_config.random || game_config.absurdle || game_config.seed != DEFAULT_SEED {
                 panic!("Contradictory parameters!")
             }
-            game.word = game_config.word.to_lowercase();
-            game.execute_game_test(&game_config, &acceptable_dictionary);
+            game.start(&parse_words(&game_config.word));
+            game.execute_game_test(&game_config, &final_dictionary, &acceptable_dictionary);
+            if game_config.keeps_history() {
+                record_game(&game_config, &game)?;
+            }
         }
-        else if game_config.random == false {  
-            if game_config.seed != DEFAULT_SEED || !game_config.state.is_empty() {
+        else if !game_config.random {  
+            if game_config.seed != DEFAULT_SEED {
                 panic!("Contradictory parameters!")
             }  
             loop {
-                let mut read_word = String::new();
-                std::io::stdin().read_line(&mut read_word).unwrap();
-                game.word = read_word.clone().to_lowercase();
-                game.update();
-                game.color_initialization();
-                game.execute_game_test(&game_config, &acceptable_dictionary);
+                if game_config.absurdle {
+                    game.start_absurdle(&final_dictionary);
+                } else {
+                    let words: Vec<String> = (0..game_config.answers_per_game()).map(|_| game.ask_for_answer(is_tty)).collect();
+                    game.start(&words);
+                }
+                game.execute_game_test(&game_config, &final_dictionary, &acceptable_dictionary);
+                if game_config.keeps_history() {
+                    record_game(&game_config, &game)?;
+                }
                 
                 let mut ans = String::new();
                 io::stdin().read_line(&mut ans).unwrap();
@@ -838,41 +2381,32 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 if ans == "Y" {
                     continue;
                 }
-                else if ans == "N" || ans.len() == 0 {
+                else if ans == "N" || ans.is_empty() {
                     break;
                 }
             }
         }
         else {
+            if game_config.absurdle {
+                panic!("Contradictory parameters!")
+            }
             let mut day: u64 = game_config.day;
             day -= 1;
-            let seed: u64 = game_config.seed;
-            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
-            let mut array = final_dictionary.clone();
-            array.shuffle(&mut rng);
+            let array = shuffle_answers(&final_dictionary, &game_config);
             
             loop {
-                if day > array.len() as u64 - 1 {
-                    day -= array.len() as u64;
-                }
-                game.word = array[day as usize].to_string();
-                game.update();
-                game.color_initialization();
-                game.execute_game_test(&game_config, &acceptable_dictionary);
+                day %= array.len() as u64;
+                // Each round takes as many consecutive words of the shuffled list as there are boards.
+                let words: Vec<String> = (0..game_config.answers_per_game())
+                    .map(|board| array[(day as usize * game_config.answers_per_game() + board) % array.len()].clone())
+                    .collect();
+                game.seed = Some(game_config.seed);
+                game.day = Some(day + 1);
+                game.start(&words);
+                game.execute_game_test(&game_config, &final_dictionary, &acceptable_dictionary);
                 
-                if !game_config.state.is_empty() {
-                    if let Err(e) = File::open(&game_config.state) { panic!("{}", e); }
-                    let filename = File::open(&game_config.state).unwrap();
-                    let mut game_json: Games = serde_json::from_reader(filename).unwrap();
-                    game_json.total_rounds += 1;
-                    let mut single_game: Game = Game::new();
-                    single_game.answer = game.word.to_uppercase();
-                    single_game.guesses = game.guesses.iter().map(|x| x.to_uppercase()).collect();
-                    game_json.games.push(single_game);
-                    if let Err(e) = File::open(&game_config.state) { panic!("{}", e); }
-                    let file = OpenOptions::new().write(true).create(true).open(&game_config.state)?;
-                    let buf_writer = BufWriter::new(file);
-                    serde_json::to_writer_pretty(buf_writer, &game_json).unwrap();
+                if game_config.keeps_history() {
+                    record_game(&game_config, &game)?;
                 }
                 
                 let mut ans = String::new();
@@ -882,7 +2416,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     day += 1;
                     continue;
                 }
-                else if ans == "N" || ans.len() == 0 {
+                else if ans == "N" || ans.is_empty() {
                     break;
                 }
             }
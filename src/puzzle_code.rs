@@ -0,0 +1,106 @@
+use crate::constraints::Difficulty;
+
+// The letters of a code: no "I", "O", "0" or "1", which are easily mistaken for each other.
+const ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+// A puzzle shared as a code, so that the answer does not show up in the shell history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleCode {
+    pub answer: String,
+    pub difficulty: Difficulty,
+    pub max_tries: usize,
+}
+
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0x5a, |sum: u8, byte| sum.rotate_left(3) ^ byte)
+}
+
+// To scramble the settings with a generator seeded by the checksum,
+// so that similar puzzles give codes looking nothing alike.
+fn scramble(bytes: &mut [u8], seed: u8) {
+    let mut state: u32 = 0x9e37_79b9 ^ (seed as u32).wrapping_mul(0x0100_0193);
+    for byte in bytes {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        *byte ^= (state >> 24) as u8;
+    }
+}
+
+fn difficulty_byte(difficulty: Difficulty) -> u8 {
+    match difficulty {
+        Difficulty::Normal => 0,
+        Difficulty::Hard => 1,
+        Difficulty::UltraHard => 2,
+    }
+}
+
+impl PuzzleCode {
+    pub fn new(answer: &str, difficulty: Difficulty, max_tries: usize) -> Self {
+        Self { answer: answer.to_string(), difficulty, max_tries }
+    }
+
+    // To encode the length, difficulty, limit of tries and answer, behind a checksum.
+    pub fn encode(&self) -> Result<String, String> {
+        let max_tries = u16::try_from(self.max_tries).map_err(|_| format!("{} tries cannot be shared", self.max_tries))?;
+        let mut bytes = vec![self.answer.len() as u8, difficulty_byte(self.difficulty)];
+        bytes.extend(max_tries.to_be_bytes());
+        bytes.extend(self.answer.bytes());
+        let sum = checksum(&bytes);
+        scramble(&mut bytes, sum);
+        bytes.insert(0, sum);
+
+        let mut code = String::new();
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+        for byte in bytes {
+            buffer = buffer << 8 | byte as u32;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                code.push(ALPHABET[(buffer >> bits & 31) as usize] as char);
+            }
+        }
+        if bits > 0 {
+            code.push(ALPHABET[(buffer << (5 - bits) & 31) as usize] as char);
+        }
+        Ok(code)
+    }
+
+    // To decode a code, ignoring case, spaces and dashes.
+    pub fn decode(code: &str) -> Result<Self, String> {
+        let invalid = || format!("\"{}\" is not a valid puzzle code", code.trim());
+        let mut bytes = Vec::new();
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+        for c in code.chars().filter(|c| !c.is_whitespace() && *c != '-') {
+            let value = ALPHABET.iter().position(|letter| *letter as char == c.to_ascii_uppercase()).ok_or_else(invalid)?;
+            buffer = buffer << 5 | value as u32;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((buffer >> bits) as u8);
+            }
+        }
+        if bytes.len() < 5 {
+            return Err(invalid());
+        }
+        let sum = bytes.remove(0);
+        scramble(&mut bytes, sum);
+        if checksum(&bytes) != sum {
+            return Err(invalid());
+        }
+        let answer = String::from_utf8(bytes[4..].to_vec()).map_err(|_| invalid())?;
+        if bytes[0] as usize != answer.len() || !answer.bytes().all(|c| c.is_ascii_lowercase()) {
+            return Err(invalid());
+        }
+        let difficulty = match bytes[1] {
+            0 => Difficulty::Normal,
+            1 => Difficulty::Hard,
+            2 => Difficulty::UltraHard,
+            _ => return Err(invalid()),
+        };
+        let max_tries = u16::from_be_bytes([bytes[2], bytes[3]]) as usize;
+        Ok(Self { answer, difficulty, max_tries })
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use crate::feedback::{evaluate, evaluate_code, Feedback, TileState};
+use crate::round::{check_word, tries_left, GuessError};
+
+// To list the acceptable words giving the row against the answer.
+pub fn solutions(answer: &str, row: &Feedback, acceptable: &[String]) -> Vec<String> {
+    acceptable.iter()
+        .filter(|word| word.len() == answer.len() && evaluate_code(answer, word) == row.code())
+        .cloned()
+        .collect()
+}
+
+// To list the rows no acceptable word gives against the answer, as 0-based positions.
+pub fn unsolvable_rows(answer: &str, rows: &[Feedback], acceptable: &[String]) -> Vec<usize> {
+    (0..rows.len()).filter(|&row| solutions(answer, &rows[row], acceptable).is_empty()).collect()
+}
+
+// To pick up to "n_rows" different rows, each of them given by an acceptable word against the answer.
+// The all-green row is left out, and the rows are sorted by their greens then yellows, as in a real game.
+pub fn generate(answer: &str, acceptable: &[String], n_rows: usize, rng: &mut StdRng) -> Vec<Feedback> {
+    let codes: BTreeSet<u32> = acceptable.iter()
+        .filter(|word| word.len() == answer.len())
+        .map(|word| evaluate_code(answer, word))
+        .collect();
+    let mut rows: Vec<Feedback> = codes.into_iter()
+        .map(|code| Feedback::from_code(code, answer.len()))
+        .filter(|row| !row.is_solved())
+        .collect();
+    rows.shuffle(rng);
+    rows.truncate(n_rows);
+    let count = |row: &Feedback, state: TileState| row.tiles().iter().filter(|tile| **tile == state).count();
+    rows.sort_by_key(|row| (count(row, TileState::Green), count(row, TileState::Yellow), row.code()));
+    rows
+}
+
+// To apply the rules of a reverse puzzle: the answer is known, and the player must enter
+// for every row in turn a word giving exactly that row against the answer.
+// Every entry not giving the current row is a wasted try.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    answer: String,
+    rows: Vec<Feedback>,
+    max_tries: usize,
+    entries: Vec<(String, Feedback)>,
+    solved: Vec<String>,
+}
+
+impl Puzzle {
+    pub fn new(answer: &str, rows: Vec<Feedback>, max_tries: usize) -> Self {
+        Self {
+            answer: answer.to_string(),
+            rows,
+            max_tries,
+            entries: Vec::new(),
+            solved: Vec::new(),
+        }
+    }
+
+    // To check an entry in the order: length, then dictionary.
+    pub fn check(&self, guess: &str, acceptable: &[String]) -> Result<(), GuessError> {
+        check_word(guess, self.answer.len(), acceptable)
+    }
+
+    // To score an accepted entry, moving on to the next row if it gives the current one.
+    pub fn play(&mut self, guess: &str) -> &Feedback {
+        let feedback = evaluate(&self.answer, guess);
+        if self.target() == Some(&feedback) {
+            self.solved.push(guess.to_string());
+        }
+        self.entries.push((guess.to_string(), feedback));
+        &self.entries[self.entries.len() - 1].1
+    }
+
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    pub fn rows(&self) -> &[Feedback] {
+        &self.rows
+    }
+
+    // The row to give next, or "None" once every row is given.
+    pub fn target(&self) -> Option<&Feedback> {
+        self.rows.get(self.solved.len())
+    }
+
+    // The words giving each row so far.
+    pub fn solved(&self) -> &[String] {
+        &self.solved
+    }
+
+    pub fn entries(&self) -> &[(String, Feedback)] {
+        &self.entries
+    }
+
+    pub fn mistakes(&self) -> usize {
+        self.entries.len() - self.solved.len()
+    }
+
+    // Only the mistakes use up tries.
+    pub fn tries_left(&self) -> Option<usize> {
+        tries_left(self.max_tries, self.mistakes())
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.solved.len() == self.rows.len()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.is_won() || self.tries_left() == Some(0)
+    }
+}
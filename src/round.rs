@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::constraints::{Constraints, Difficulty, Violation};
+use crate::feedback::{evaluate, evaluate_code, evaluate_union, Feedback, KeyboardState, TileState};
+
+// The number of tries meaning that a round never runs out of tries.
+pub const UNLIMITED_TRIES: usize = 0;
+
+// To describe why a guess is not accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessError {
+    WrongLength(usize),
+    NotInDictionary,
+    Violation(Violation),
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::WrongLength(length) => write!(f, "Your guess must be {} letters", length),
+            GuessError::NotInDictionary => write!(f, "The word isn't in the Wordle dictionary"),
+            GuessError::Violation(violation) => write!(f, "{}", violation),
+        }
+    }
+}
+
+// To check that a guess has the given length and is in the dictionary.
+pub fn check_word(guess: &str, length: usize, acceptable: &[String]) -> Result<(), GuessError> {
+    if guess.len() != length {
+        return Err(GuessError::WrongLength(length));
+    }
+    if !acceptable.iter().any(|word| word.trim() == guess) {
+        return Err(GuessError::NotInDictionary);
+    }
+    Ok(())
+}
+
+// The tries left after "used" of "max_tries" tries. Returns "None" when there is no limit.
+pub fn tries_left(max_tries: usize, used: usize) -> Option<usize> {
+    if max_tries == UNLIMITED_TRIES {
+        None
+    } else {
+        Some(max_tries.saturating_sub(used))
+    }
+}
+
+// To apply the rules of a single game: validating, scoring and counting the guesses.
+#[derive(Debug, Clone)]
+pub struct Round {
+    answer: String,
+    difficulty: Difficulty,
+    max_tries: usize,
+    history: Vec<(String, Feedback)>,
+    keyboard: KeyboardState,
+    constraints: Constraints,
+    // In "Absurdle Mode", the answers still consistent with every feedback given so far.
+    candidates: Option<Vec<String>>,
+    // In "Fibble Mode", the generator choosing the lying tiles, and the lying tile of every row.
+    liar: Option<StdRng>,
+    lies: Vec<usize>,
+    // In "Xordle Mode", the second answer, sharing no letter with the first one.
+    second: Option<String>,
+}
+
+// In "Fibble Mode", to change the state of one tile of the feedback, returning its position.
+// The lie never turns the row all green, so a row looking solved always is.
+fn lie(feedback: &Feedback, rng: &mut StdRng) -> (Feedback, usize) {
+    let mut tiles = feedback.tiles().to_vec();
+    let position = rng.gen_range(0..tiles.len());
+    let others: Vec<TileState> = [TileState::Red, TileState::Yellow, TileState::Green].into_iter()
+        .filter(|state| *state != tiles[position])
+        .collect();
+    let choice = rng.gen_range(0..others.len());
+    tiles[position] = others[choice];
+    if tiles.iter().all(|tile| *tile == TileState::Green) {
+        tiles[position] = others[1 - choice];
+    }
+    (Feedback::new(tiles), position)
+}
+
+// In "Absurdle Mode", to choose the feedback keeping the most candidates.
+// Ties go to the feedback revealing less: fewer greens, then fewer yellows, then the lowest code.
+fn adversarial_bucket(candidates: &[String], guess: &str) -> Vec<String> {
+    let mut buckets: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+    for candidate in candidates {
+        buckets.entry(evaluate_code(candidate, guess)).or_default().push(candidate.clone());
+    }
+    let count = |code: u32, state: TileState| {
+        Feedback::from_code(code, guess.len()).tiles().iter().filter(|tile| **tile == state).count()
+    };
+    let (_, bucket) = buckets.into_iter()
+        .min_by_key(|(code, bucket)| (std::cmp::Reverse(bucket.len()), count(*code, TileState::Green), count(*code, TileState::Yellow), *code))
+        .unwrap();
+    bucket
+}
+
+impl Round {
+    pub fn new(answer: &str, difficulty: Difficulty, max_tries: usize) -> Self {
+        Self {
+            answer: answer.to_string(),
+            difficulty,
+            max_tries,
+            history: Vec::new(),
+            keyboard: KeyboardState::new(),
+            constraints: Constraints::new(answer.len()),
+            candidates: None,
+            liar: None,
+            lies: Vec::new(),
+            second: None,
+        }
+    }
+
+    // In "Xordle Mode", every guess is scored against both answers at once,
+    // and the round is won once both of them have been guessed.
+    pub fn xordle(first: &str, second: &str, difficulty: Difficulty, max_tries: usize) -> Self {
+        let mut round = Self::new(first, difficulty, max_tries);
+        round.second = Some(second.to_string());
+        round
+    }
+
+    // In "Anti Mode", the player must avoid the answer for as long as possible:
+    // every guess keeps to all the hints so far, and the round is only over once the answer is guessed.
+    pub fn anti(answer: &str) -> Self {
+        Self::new(answer, Difficulty::UltraHard, UNLIMITED_TRIES)
+    }
+
+    // In "Absurdle Mode", no answer is chosen up front: every guess gets the feedback
+    // keeping the most candidates, until a single word is left and gets guessed.
+    // The answer list must not be empty.
+    pub fn adversarial(answers: &[String], difficulty: Difficulty, max_tries: usize) -> Self {
+        let mut round = Self::new(&answers[0], difficulty, max_tries);
+        round.candidates = Some(answers.to_vec());
+        round
+    }
+
+    // In "Fibble Mode", exactly one tile of every row lies, except in the row guessing the answer.
+    // The lies are drawn from a generator seeded with the given seed.
+    pub fn fibble(answer: &str, difficulty: Difficulty, max_tries: usize, seed: u64) -> Self {
+        let mut round = Self::new(answer, difficulty, max_tries);
+        round.liar = Some(StdRng::seed_from_u64(seed));
+        round
+    }
+
+    // To check a guess in the order: length, dictionary, then the rules of the difficulty.
+    pub fn check(&self, guess: &str, acceptable: &[String]) -> Result<(), GuessError> {
+        check_word(guess, self.answer.len(), acceptable)?;
+        self.constraints.check(guess, self.difficulty).map_err(GuessError::Violation)
+    }
+
+    // To score an accepted guess and update the state of all letters.
+    // In "Absurdle Mode", the answer is first replaced by a word of the chosen feedback.
+    // In "Fibble Mode", the feedback returned and recorded is the lying one,
+    // so the keyboard and the constraints only know what the player is shown.
+    pub fn play(&mut self, guess: &str) -> &Feedback {
+        if let Some(candidates) = &self.candidates {
+            let bucket = adversarial_bucket(candidates, guess);
+            self.answer = bucket[0].clone();
+            self.candidates = Some(bucket);
+        }
+        let mut feedback = match &self.second {
+            Some(second) => evaluate_union(&[&self.answer, second], guess),
+            None => evaluate(&self.answer, guess),
+        };
+        if let Some(rng) = &mut self.liar {
+            if !feedback.is_solved() {
+                let (lying, position) = lie(&feedback, rng);
+                feedback = lying;
+                self.lies.push(position);
+            }
+        }
+        self.keyboard.update(guess, &feedback);
+        self.constraints.update(guess, &feedback);
+        self.history.push((guess.to_string(), feedback));
+        &self.history[self.history.len() - 1].1
+    }
+
+    // In "Absurdle Mode", one of the remaining candidates until a single one is left.
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    // All the answers to guess: two of them in "Xordle Mode".
+    pub fn answers(&self) -> Vec<&str> {
+        let mut answers = vec![self.answer.as_str()];
+        answers.extend(self.second.as_deref());
+        answers
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    pub fn max_tries(&self) -> usize {
+        self.max_tries
+    }
+
+    pub fn history(&self) -> &[(String, Feedback)] {
+        &self.history
+    }
+
+    pub fn is_fibble(&self) -> bool {
+        self.liar.is_some()
+    }
+
+    // In "Fibble Mode", the position of the lying tile of every row but a winning one.
+    pub fn lies(&self) -> &[usize] {
+        &self.lies
+    }
+
+    pub fn keyboard(&self) -> &KeyboardState {
+        &self.keyboard
+    }
+
+    pub fn tries(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn tries_left(&self) -> Option<usize> {
+        tries_left(self.max_tries, self.tries())
+    }
+
+    // In "Anti Mode", the score: the number of guesses not hitting an answer.
+    pub fn survived(&self) -> usize {
+        let answers = self.answers();
+        self.history.iter().filter(|(guess, _)| !answers.contains(&guess.as_str())).count()
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.answers().iter().all(|answer| self.history.iter().any(|(guess, _)| guess == answer))
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.is_won() || self.tries_left() == Some(0)
+    }
+}
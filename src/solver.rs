@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+use serde::Serialize;
+use crate::constraints::{Constraints, Difficulty};
+use crate::feedback::{evaluate, evaluate_code, Feedback};
+
+// A guess together with the feedback it received.
+pub type History = Vec<(String, Feedback)>;
+
+// To parse a history such as "crane:RRYGR,moist:RGRRR".
+pub fn parse_history(text: &str) -> Result<History, String> {
+    let mut history = History::new();
+    for entry in text.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+        let (word, pattern) = entry.split_once(':')
+            .ok_or_else(|| format!("\"{}\" should look like WORD:PATTERN", entry))?;
+        let word = word.trim().to_lowercase();
+        let feedback = Feedback::parse(pattern)
+            .ok_or_else(|| format!("\"{}\" is not a pattern of G, Y and R", pattern.trim()))?;
+        if feedback.len() != word.chars().count() {
+            return Err(format!("the pattern of \"{}\" has a different length", word));
+        }
+        history.push((word, feedback));
+    }
+    Ok(history)
+}
+
+// To check if a word could still be the answer after the given history.
+pub fn is_consistent(candidate: &str, history: &[(String, Feedback)]) -> bool {
+    history.iter().all(|(guess, feedback)| {
+        guess.len() == candidate.len() && evaluate_code(candidate, guess) == feedback.code()
+    })
+}
+
+// To keep the answers that are consistent with the history.
+pub fn filter_candidates(answers: &[String], history: &[(String, Feedback)]) -> Vec<String> {
+    answers.iter().filter(|word| is_consistent(word, history)).cloned().collect()
+}
+
+// In "Fibble Mode", to check if a word could still be the answer when exactly one tile of every row lies.
+// A row guessing the answer is the only one telling the truth.
+pub fn is_consistent_with_lies(candidate: &str, history: &[(String, Feedback)]) -> bool {
+    history.iter().all(|(guess, feedback)| {
+        if guess.len() != candidate.len() {
+            return false;
+        }
+        let truth = evaluate(candidate, guess);
+        let n_lies = truth.tiles().iter().zip(feedback.tiles()).filter(|(a, b)| a != b).count();
+        n_lies == if truth.is_solved() { 0 } else { 1 }
+    })
+}
+
+// In "Fibble Mode", to keep the answers that are consistent with the history, one tile of every row lying.
+pub fn filter_candidates_with_lies(answers: &[String], history: &[(String, Feedback)]) -> Vec<String> {
+    answers.iter().filter(|word| is_consistent_with_lies(word, history)).cloned().collect()
+}
+
+// To calculate the expected information (in bits) revealed by a guess,
+// i.e. the entropy of the feedback over equally likely candidates.
+pub fn expected_information(guess: &str, candidates: &[String]) -> f64 {
+    if candidates.is_empty() {
+        return 0.0;
+    }
+    // Sorting the codes groups equal feedback together and keeps the sum in a stable order.
+    let mut codes: Vec<u32> = candidates.iter().map(|candidate| evaluate_code(candidate, guess)).collect();
+    codes.sort_unstable();
+    let total = candidates.len() as f64;
+    codes.chunk_by(|a, b| a == b).map(|group| {
+        let p = group.len() as f64 / total;
+        -p * p.log2()
+    }).sum()
+}
+
+// A guess with its expected information.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RankedGuess {
+    pub word: String,
+    pub entropy: f64,
+    pub candidate: bool,
+}
+
+// To rank guesses by expected information, best first.
+// Ties are broken in favour of possible answers, then alphabetically.
+pub fn rank_guesses(guesses: &[String], candidates: &[String]) -> Vec<RankedGuess> {
+    let possible: HashSet<&String> = candidates.iter().collect();
+    let mut ranked: Vec<RankedGuess> = guesses.iter().map(|guess| RankedGuess {
+        word: guess.clone(),
+        entropy: expected_information(guess, candidates),
+        candidate: possible.contains(guess),
+    }).collect();
+    ranked.sort_by(|a, b| {
+        b.entropy.partial_cmp(&a.entropy).unwrap()
+            .then(b.candidate.cmp(&a.candidate))
+            .then(a.word.cmp(&b.word))
+    });
+    ranked
+}
+
+// To list the guesses allowed by the rules of the given difficulty.
+pub fn allowed_guesses(acceptable: &[String], history: &[(String, Feedback)], difficulty: Difficulty) -> Vec<String> {
+    if difficulty == Difficulty::Normal || history.is_empty() {
+        return acceptable.to_vec();
+    }
+    let mut constraints = Constraints::new(history[0].0.len());
+    for (guess, feedback) in history {
+        constraints.update(guess, feedback);
+    }
+    acceptable.iter().filter(|word| constraints.check(word, difficulty).is_ok()).cloned().collect()
+}
+
+// The number of words of each list given by a hint without a limit.
+pub const DEFAULT_HINTS: usize = 10;
+
+// To parse the "hint" command with an optional limit, e.g. "hint 10".
+pub fn parse_hint(input: &str) -> Option<usize> {
+    let parts: Vec<&str> = input.split_whitespace().collect();
+    match parts.as_slice() {
+        [command] if command.eq_ignore_ascii_case("hint") => Some(DEFAULT_HINTS),
+        [command, limit] if command.eq_ignore_ascii_case("hint") => limit.parse().ok(),
+        _ => None,
+    }
+}
+
+// The words given by a hint: the possible answers ranked by how well they split the candidates,
+// then the words that cannot be the answer but split them even better than the best possible answer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hints {
+    pub answers: Vec<RankedGuess>,
+    pub probes: Vec<RankedGuess>,
+}
+
+// To choose up to "limit" words of each list of a hint among the allowed guesses.
+pub fn hints(candidates: &[String], guesses: &[String], limit: usize) -> Hints {
+    let ranked = rank_guesses(guesses, candidates);
+    let answers: Vec<RankedGuess> = ranked.iter().filter(|guess| guess.candidate).take(limit).cloned().collect();
+    let best = answers.first().map_or(0.0, |guess| guess.entropy);
+    let probes = ranked.into_iter().filter(|guess| !guess.candidate && guess.entropy > best).take(limit).collect();
+    Hints { answers, probes }
+}
+
+// The machine-readable result of the "solve" subcommand.
+#[derive(Debug, Clone, Serialize)]
+pub struct SolveReport {
+    pub remaining: usize,
+    pub candidates: Vec<String>,
+    pub guesses: Vec<RankedGuess>,
+}
+
+// To rank the best next guesses after the given history, keeping the first "top" of each list.
+pub fn solve(answers: &[String], acceptable: &[String], history: &[(String, Feedback)], difficulty: Difficulty, top: usize) -> SolveReport {
+    let candidates = filter_candidates(answers, history);
+    let guesses = allowed_guesses(acceptable, history, difficulty);
+    let mut ranked = rank_guesses(&guesses, &candidates);
+    ranked.truncate(top);
+    SolveReport {
+        remaining: candidates.len(),
+        candidates: candidates.into_iter().take(top).collect(),
+        guesses: ranked,
+    }
+}
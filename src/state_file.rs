@@ -0,0 +1,55 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn sibling(path: &str, extension: &str) -> String {
+    format!("{}.{}", path, extension)
+}
+
+// An advisory lock on a state file, released when dropped.
+// The lock is taken on a separate ".lock" file, since the state file itself is replaced on every save.
+pub struct StateLock {
+    _file: File,
+}
+
+// To wait until no other process holds the lock of the state file.
+pub fn lock(path: &str) -> io::Result<StateLock> {
+    let file = OpenOptions::new().write(true).create(true).truncate(false).open(sibling(path, "lock"))?;
+    file.lock()?;
+    Ok(StateLock { _file: file })
+}
+
+fn read<T: DeserializeOwned>(path: &str) -> io::Result<T> {
+    let text = fs::read_to_string(path)?;
+    serde_json::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, e)))
+}
+
+// To read a state file, falling back on its ".bak" backup when it is missing or cannot be parsed,
+// e.g. after a crash in the middle of a write. Returns whether the backup was used.
+// Without a file or a backup, the error of the file itself is returned.
+pub fn load<T: DeserializeOwned>(path: &str) -> io::Result<(T, bool)> {
+    match read(path) {
+        Ok(value) => Ok((value, false)),
+        Err(e) => match read(&sibling(path, "bak")) {
+            Ok(value) => Ok((value, true)),
+            Err(_) => Err(e),
+        },
+    }
+}
+
+// To replace a state file in a single step: the new content is written to a ".tmp" file, flushed to disk,
+// then renamed over the state file, so that readers see either the old or the new content.
+// The old content is kept as the ".bak" backup first, unless it cannot be parsed.
+pub fn save<T: Serialize>(path: &str, value: &T) -> io::Result<()> {
+    let text = serde_json::to_string_pretty(value).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let temporary = sibling(path, "tmp");
+    let mut file = File::create(&temporary)?;
+    file.write_all(text.as_bytes())?;
+    file.sync_all()?;
+    drop(file);
+    if read::<serde_json::Value>(path).is_ok() {
+        fs::copy(path, sibling(path, "bak"))?;
+    }
+    fs::rename(&temporary, path)
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use crate::constraints::Difficulty;
+use crate::feedback::{evaluate, evaluate_code, Feedback};
+use crate::solver;
+
+// A node of the decision tree: the guess to play, and the node to go on with for each feedback.
+// The all-green feedback has no child, since the game is won.
+// The tree is built greedily, one most informative guess at a time: it is not the tree with the fewest guesses,
+// and nothing bounds its depth, so its worst case is only known once it is built.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecisionTree {
+    pub guess: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub children: BTreeMap<String, DecisionTree>,
+}
+
+// The exported strategy together with its settings and statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreeExport {
+    pub difficulty: Difficulty,
+    pub words: usize,
+    pub average: f64,
+    pub worst: usize,
+    pub tree: DecisionTree,
+}
+
+// To choose the guess for a node.
+// Words left with no more information to gain (at most 2 of them, or none telling them apart) are guessed directly.
+fn choose_guess(candidates: &[String], acceptable: &[String], history: &[(String, Feedback)], difficulty: Difficulty) -> String {
+    if candidates.len() > 2 {
+        let guesses = solver::allowed_guesses(acceptable, history, difficulty);
+        if let Some(best) = solver::rank_guesses(&guesses, candidates).into_iter().next() {
+            if best.entropy > 0.0 {
+                return best.word;
+            }
+        }
+    }
+    candidates[0].clone()
+}
+
+fn build_node(guess: String, candidates: &[String], acceptable: &[String], history: &mut Vec<(String, Feedback)>, difficulty: Difficulty) -> DecisionTree {
+    let mut groups: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+    for candidate in candidates {
+        groups.entry(evaluate_code(candidate, &guess)).or_default().push(candidate.clone());
+    }
+    let mut children = BTreeMap::new();
+    for (code, group) in groups {
+        let feedback = Feedback::from_code(code, guess.len());
+        if feedback.is_solved() {
+            continue;
+        }
+        history.push((guess.clone(), feedback.clone()));
+        let next = choose_guess(&group, acceptable, history, difficulty);
+        children.insert(feedback.to_string(), build_node(next, &group, acceptable, history, difficulty));
+        history.pop();
+    }
+    DecisionTree { guess, children }
+}
+
+impl DecisionTree {
+    // To build a tree solving every answer, starting from the opener.
+    // At every node the guess with the most expected information is chosen greedily.
+    pub fn build(opener: &str, answers: &[String], acceptable: &[String], difficulty: Difficulty) -> Self {
+        build_node(opener.to_string(), answers, acceptable, &mut Vec::new(), difficulty)
+    }
+
+    // To list the tree's choice at every step of a player's history and for the next guess,
+    // e.g. to audit the player's choices. The list stops early once the player leaves the tree.
+    pub fn recommendations(&self, history: &[(String, Feedback)]) -> Vec<String> {
+        let mut result = Vec::new();
+        let mut node = Some(self);
+        for (guess, feedback) in history {
+            let Some(current) = node else { break };
+            result.push(current.guess.clone());
+            node = if *guess == current.guess { current.children.get(&feedback.to_string()) } else { None };
+        }
+        if let Some(current) = node {
+            result.push(current.guess.clone());
+        }
+        result
+    }
+
+    // To play a game against the answer, returning all guesses made.
+    // Returns "None" if the answer is not covered by the tree.
+    pub fn play(&self, answer: &str) -> Option<Vec<String>> {
+        let mut node = self;
+        let mut guesses = Vec::new();
+        loop {
+            guesses.push(node.guess.clone());
+            let feedback = evaluate(answer, &node.guess);
+            if feedback.is_solved() {
+                return Some(guesses);
+            }
+            node = node.children.get(&feedback.to_string())?;
+        }
+    }
+}
+
+impl TreeExport {
+    // To wrap a tree with its average and worst number of guesses over the answers.
+    pub fn new(tree: DecisionTree, answers: &[String], difficulty: Difficulty) -> Result<Self, String> {
+        let mut total = 0;
+        let mut worst = 0;
+        for answer in answers {
+            let guesses = tree.play(answer).ok_or_else(|| format!("the tree cannot solve {}", answer.to_uppercase()))?;
+            total += guesses.len();
+            worst = worst.max(guesses.len());
+        }
+        Ok(Self {
+            difficulty,
+            words: answers.len(),
+            average: if answers.is_empty() { 0.0 } else { total as f64 / answers.len() as f64 },
+            worst,
+            tree,
+        })
+    }
+}
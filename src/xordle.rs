@@ -0,0 +1,32 @@
+use crate::feedback::{evaluate_union_code, Feedback};
+
+// To describe the letters of a word as a bit set, 'a' being the lowest bit.
+fn letters(word: &str) -> u32 {
+    word.bytes().filter(u8::is_ascii_lowercase).fold(0, |set, c| set | 1 << (c - b'a'))
+}
+
+// To list every pair of answers without any letter in common, in the order of the answer list.
+pub fn disjoint_pairs(answers: &[String]) -> Vec<(String, String)> {
+    let sets: Vec<u32> = answers.iter().map(|word| letters(word)).collect();
+    let mut pairs = Vec::new();
+    for i in 0..answers.len() {
+        for j in i + 1..answers.len() {
+            if sets[i] & sets[j] == 0 && answers[i].len() == answers[j].len() {
+                pairs.push((answers[i].clone(), answers[j].clone()));
+            }
+        }
+    }
+    pairs
+}
+
+// To check if a pair could still be the answers after the given history.
+pub fn is_consistent_pair(pair: &(String, String), history: &[(String, Feedback)]) -> bool {
+    history.iter().all(|(guess, feedback)| {
+        guess.len() == pair.0.len() && evaluate_union_code(&[&pair.0, &pair.1], guess) == feedback.code()
+    })
+}
+
+// To keep the pairs that are consistent with the history.
+pub fn filter_pairs(pairs: &[(String, String)], history: &[(String, Feedback)]) -> Vec<(String, String)> {
+    pairs.iter().filter(|pair| is_consistent_pair(pair, history)).cloned().collect()
+}
@@ -0,0 +1,49 @@
+use wordle::bench::{self, FirstStrategy, RandomStrategy, TreeStrategy};
+use wordle::tree::DecisionTree;
+use wordle::Difficulty;
+
+mod helpers;
+use helpers::words;
+
+#[test]
+fn test_bench_first_strategy() {
+    let answers = words(&["bake", "cake", "lake", "make"]);
+    let report = bench::run(&mut FirstStrategy::new(&answers), &answers, &answers, Difficulty::Normal, 2);
+    assert_eq!(report.games, 4);
+    assert_eq!(report.wins, 2);
+    assert_eq!(report.histogram.clone().into_iter().collect::<Vec<_>>(), vec![(1, 1), (2, 1)]);
+    assert_eq!(report.failed, words(&["lake", "make"]));
+    assert!((report.mean_guesses() - 1.5).abs() < 1e-9);
+    assert!((report.win_rate() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_bench_entropy_strategy() {
+    let answers = words(&["bake", "cake", "lake", "make", "able"]);
+    let acceptable = words(&["able", "bake", "bcml", "cake", "lake", "make"]);
+    let mut strategy = TreeStrategy::entropy(Some("bake"), &answers, &acceptable, Difficulty::Normal);
+    let report = bench::run(&mut strategy, &answers, &acceptable, Difficulty::Normal, 6);
+    assert_eq!(report.wins, 5);
+    assert_eq!(report.total_guesses, 12);
+}
+
+#[test]
+fn test_bench_follows_difficult_rules() {
+    let answers = words(&["bake", "cake", "lake", "make", "able"]);
+    let acceptable = words(&["able", "bake", "bcml", "cake", "lake", "make"]);
+    // the tree is built for the normal mode, so its probe "bcml" is rejected in difficult mode
+    let tree = DecisionTree::build("bake", &answers, &acceptable, Difficulty::Normal);
+    let round = bench::play(&mut TreeStrategy::new(tree), "make", &acceptable, Difficulty::Hard, 6);
+    assert!(!round.is_won());
+    assert_eq!(round.tries(), 1);
+}
+
+#[test]
+fn test_bench_random_strategy_is_seeded() {
+    let answers = words(&["bake", "cake", "lake", "make", "able"]);
+    let first = bench::run(&mut RandomStrategy::new(&answers, 7), &answers, &answers, Difficulty::Hard, 0);
+    let second = bench::run(&mut RandomStrategy::new(&answers, 7), &answers, &answers, Difficulty::Hard, 0);
+    assert_eq!(first, second);
+    // a possible answer is always allowed, so every game is won without a limit
+    assert_eq!(first.wins, 5);
+}
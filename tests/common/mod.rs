@@ -9,7 +9,6 @@ use std::sync::Once;
 use assert_json_diff::assert_json_eq;
 use lazy_static::lazy_static;
 use pretty_assertions::assert_eq;
-use serde_json;
 
 // The code was originally written by Jack O'Connor (@oconnor663)
 // Taken from https://github.com/oconnor663/os_pipe.rs/blob/f41c58e503e1efc5e4d0edfcd2e756b3a81b4232/src/lib.rs#L281-L314
@@ -41,7 +40,10 @@ fn build_and_find_path(name: &str) -> PathBuf {
     static CARGO_BUILD_ONCE: Once = Once::new();
     CARGO_BUILD_ONCE.call_once(|| {
         let mut build_command = Command::new("cargo");
-        build_command.args(&["build", "--quiet"]);
+        build_command.args(["build", "--quiet"]);
+        if cfg!(feature = "sqlite") {
+            build_command.args(["--features", "sqlite"]);
+        }
         if !cfg!(debug_assertions) {
             build_command.arg("--release");
         }
@@ -100,6 +102,8 @@ impl TestCase {
     fn execute_program_and_feed_input(&self) -> Child {
         let mut command = Command::new(EXE_PATH.as_os_str())
             .args(&self.arguments)
+            // fix the time, e.g. of recorded games
+            .env("WORDLE_NOW", "1655000000")
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .spawn()
@@ -169,6 +173,23 @@ impl TestCase {
         assert_json_eq!(run_state, answer_state);
     }
 
+    // For states that cannot be compared as a whole, e.g. with timings.
+    // Not used by every test crate including this module.
+    #[allow(dead_code)]
+    pub fn run_and_read_game_state(&mut self) -> serde_json::Value {
+        let case_dir = Path::new("tests").join("cases");
+        let before_state_file = case_dir.join(format!("{}.before.json", self.name));
+        let run_state_file = case_dir.join(format!("{}.run.json", self.name));
+
+        std::fs::copy(&before_state_file, &run_state_file).unwrap();
+        self.arguments.append(&mut vec![
+            "--state".to_string(),
+            run_state_file.to_str().unwrap().to_string(),
+        ]);
+        self.run_and_compare_result();
+        serde_json::from_reader(BufReader::new(File::open(&run_state_file).unwrap())).unwrap()
+    }
+
     pub fn run_and_expect_exit(&self) {
         let command = self.execute_program_and_feed_input();
         assert!(
@@ -0,0 +1,52 @@
+use wordle::{evaluate, Constraints, Difficulty, Violation};
+
+fn constraints_after(answer: &str, guesses: &[&str]) -> Constraints {
+    let mut constraints = Constraints::new(answer.len());
+    for guess in guesses {
+        constraints.update(guess, &evaluate(answer, guess));
+    }
+    constraints
+}
+
+#[test]
+fn test_hard_mode_accumulates_hints() {
+    // 'T' is green after the first guess and must be kept after the second
+    let constraints = constraints_after("tiger", &["toast", "brine"]);
+    let violation = constraints.check("riper", Difficulty::Hard).unwrap_err();
+    assert_eq!(violation, Violation::MissingGreen { position: 0, letter: 't' });
+    assert_eq!(violation.to_string(), "1st letter must be T");
+    assert_eq!(
+        constraints.check("tunic", Difficulty::Hard).unwrap_err().to_string(),
+        "Guess must contain E"
+    );
+    assert!(constraints.check("tiger", Difficulty::Hard).is_ok());
+    assert!(constraints.check("crane", Difficulty::Normal).is_ok());
+}
+
+#[test]
+fn test_hard_mode_repeated_letters() {
+    let constraints = constraints_after("geese", &["elope"]);
+    assert_eq!(
+        constraints.check("prose", Difficulty::Hard).unwrap_err().to_string(),
+        "Guess must contain 2 Es"
+    );
+    assert!(constraints.check("erase", Difficulty::Hard).is_ok());
+}
+
+#[test]
+fn test_ultra_hard_mode() {
+    let constraints = constraints_after("tiger", &["crane"]);
+    // allowed in hard mode, but 'A' is grey
+    assert!(constraints.check("racer", Difficulty::Hard).is_ok());
+    assert_eq!(
+        constraints.check("racer", Difficulty::UltraHard).unwrap_err().to_string(),
+        "Guess must not contain A"
+    );
+    // allowed in hard mode, but 'R' was already yellow in the 2nd position
+    assert!(constraints.check("error", Difficulty::Hard).is_ok());
+    assert_eq!(
+        constraints.check("error", Difficulty::UltraHard).unwrap_err().to_string(),
+        "2nd letter must not be R"
+    );
+    assert!(constraints.check("tiger", Difficulty::UltraHard).is_ok());
+}
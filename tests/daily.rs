@@ -0,0 +1,37 @@
+use wordle::daily::{day_of, format_date, parse_date, puzzle_index, puzzle_number, DEFAULT_EPOCH};
+
+#[test]
+fn test_dates_round_trip() {
+    assert_eq!(parse_date("1970-01-01"), Ok(0));
+    assert_eq!(parse_date("1969-12-31"), Ok(-1));
+    assert_eq!(parse_date("2000-03-01"), Ok(11017));
+    for date in ["1600-02-29", "1900-02-28", "2021-06-19", "2024-12-31", "9999-01-01"] {
+        assert_eq!(format_date(parse_date(date).unwrap()), date);
+    }
+}
+
+#[test]
+fn test_invalid_dates_are_rejected() {
+    for date in ["", "2022-1", "2022-13-01", "2022-02-29", "1900-02-29", "2022-04-31", "today"] {
+        assert!(parse_date(date).is_err(), "{}", date);
+    }
+}
+
+#[test]
+fn test_puzzles_go_round_the_list_both_ways() {
+    let epoch = parse_date(DEFAULT_EPOCH).unwrap();
+    assert_eq!(puzzle_number(parse_date("2022-01-31").unwrap(), epoch), 226);
+    assert_eq!(puzzle_number(parse_date("2021-06-18").unwrap(), epoch), -1);
+    assert_eq!(puzzle_index(226, 4), 2);
+    assert_eq!(puzzle_index(-1, 4), 3);
+    assert_eq!(puzzle_index(-8, 4), 0);
+    assert_eq!(puzzle_index(i64::MAX, 4), 3);
+}
+
+#[test]
+fn test_day_of_a_time() {
+    assert_eq!(day_of(0), 0);
+    assert_eq!(format_date(day_of(1655000000)), "2022-06-12");
+    assert_eq!(format_date(day_of(1655078399)), "2022-06-12");
+    assert_eq!(format_date(day_of(1655078400)), "2022-06-13");
+}
@@ -0,0 +1,246 @@
+use ntest::timeout;
+
+mod common;
+use common::TestCase;
+
+#[test]
+#[timeout(2000)]
+fn test_09_word_length() {
+    // 4-letter game with a mixed-length word list
+    TestCase::read("09_01_word_length").run_and_compare_result();
+    // word length out of range
+    TestCase::read("09_02_invalid_length").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_10_max_tries() {
+    // fewer tries than usual
+    TestCase::read("10_01_max_tries").run_and_compare_result();
+    // unlimited tries with statistics
+    TestCase::read("10_02_unlimited_tries").run_and_compare_result();
+    // the limit is recorded in the game state
+    TestCase::read("10_03_save_max_tries").run_and_compare_game_state();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_11_ultra_hard_mode() {
+    // grey letters and repeated yellow positions are rejected
+    TestCase::read("11_01_ultra_hard_mode").run_and_compare_result();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_12_solve() {
+    // rank the next guesses with a custom word list
+    TestCase::read("12_01_solve").run_and_compare_result();
+    // history with an invalid pattern
+    TestCase::read("12_02_solve_invalid_history").run_and_expect_exit();
+    // the hints of a game are ranked and limited, and counted in its state
+    TestCase::read("12_03_hints").run_and_compare_game_state();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_13_tree() {
+    // build a tree and audit a player's guesses
+    TestCase::read("13_01_tree").run_and_compare_result();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_14_bench() {
+    // play every answer with a single try, failing all but the first
+    TestCase::read("14_01_bench").run_and_compare_result();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_15_absurdle() {
+    // the answer is chosen as late as possible, also in difficult mode
+    TestCase::read("15_01_absurdle").run_and_compare_result();
+    // no answer can be given in absurdle mode
+    TestCase::read("15_02_absurdle_with_word").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_16_boards() {
+    // two boards sharing the guesses, each finishing on its own
+    TestCase::read("16_01_boards").run_and_compare_result();
+    // all answers of a round are recorded and counted in the statistics
+    TestCase::read("16_02_save_boards").run_and_compare_game_state();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_17_fibble() {
+    // one tile of every row lies, recorded in the game state
+    TestCase::read("17_01_fibble").run_and_compare_game_state();
+    // the difficult rules cannot be applied to lying rows
+    TestCase::read("17_02_fibble_difficult").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_18_survival() {
+    // the tries left are carried over, and the run joins the high scores
+    TestCase::read("18_01_survival_carry").run_and_compare_game_state();
+    // every game has one try less than the one before
+    TestCase::read("18_02_survival_shrink").run_and_compare_result();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_19_timed() {
+    // the time of every guess and of the game is recorded
+    let state = TestCase::read("19_01_timed").run_and_read_game_state();
+    let game = &state["games"][0];
+    let guess_times: Vec<u64> = game["guess_times"].as_array().unwrap().iter().map(|time| time.as_u64().unwrap()).collect();
+    assert_eq!(guess_times.len(), 2);
+    assert_eq!(game["time"].as_u64().unwrap(), guess_times.iter().sum::<u64>());
+    // the best and average times of the timed games won
+    TestCase::read("19_02_timed_stats").run_and_compare_game_state();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_20_xordle() {
+    // every guess is scored against both answers
+    TestCase::read("20_01_xordle").run_and_compare_result();
+    // a random pair is drawn and both answers are recorded
+    TestCase::read("20_02_save_xordle").run_and_compare_game_state();
+    // the answers must not have any letter in common
+    TestCase::read("20_03_xordle_common_letters").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_21_anti() {
+    // every guess must keep to the hints, until the answer is hit
+    TestCase::read("21_01_anti").run_and_compare_result();
+    // the guesses survived are recorded and summarized
+    TestCase::read("21_02_save_anti").run_and_compare_game_state();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_22_prefill() {
+    // the prefilled guesses are replayed before the player takes over
+    TestCase::read("22_01_prefill").run_and_compare_result();
+    // the prefilled guesses are flagged in the state
+    TestCase::read("22_02_save_prefill").run_and_compare_game_state();
+    // the prefilled guesses must keep to the rules
+    TestCase::read("22_03_prefill_invalid").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_23_reverse() {
+    // every generated row is given by a word against the shown answer
+    TestCase::read("23_01_reverse").run_and_compare_result();
+    // the rows can be given, and a wrong word wastes a try
+    TestCase::read("23_02_reverse_rows").run_and_compare_result();
+    // a row without any solution is reported
+    TestCase::read("23_03_reverse_unsolvable").run_and_expect_exit();
+    // the words entered and the rows to give are saved as a reverse game
+    TestCase::read("23_04_reverse_state").run_and_compare_game_state();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_24_puzzle_code() {
+    // the code hides the answer and the settings
+    TestCase::read("24_01_create").run_and_compare_result();
+    // the code is played with its own length, difficulty and tries
+    TestCase::read("24_02_play_code").run_and_compare_result();
+    // the answer of the code must be in the word list
+    TestCase::read("24_03_code_word_list").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_25_daily() {
+    // the puzzle of a date is numbered from the epoch and recorded with its date
+    TestCase::read("25_01_daily").run_and_compare_game_state();
+    // a daily already in the state cannot be played again
+    TestCase::read("25_02_daily_replayed").run_and_expect_exit();
+    // the epoch can be configured
+    TestCase::read("25_03_daily_epoch").run_and_compare_result();
+    // the day of a random answer cannot be given with a date
+    TestCase::read("25_04_daily_day").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_26_review() {
+    // every saved game is replayed with the letters and possible answers after each guess
+    TestCase::read("26_01_review").run_and_compare_result();
+    // a single game can be replayed, with a row for each board
+    TestCase::read("26_02_review_game").run_and_compare_result();
+    // the game must be in the state file
+    TestCase::read("26_03_review_missing_game").run_and_expect_exit();
+    // games played with other words are skipped, as their possible answers cannot be counted again
+    TestCase::read("26_04_review_other_words").run_and_compare_result();
+    // and cannot be replayed on their own
+    TestCase::read("26_05_review_game_other_words").run_and_expect_exit();
+    // a reverse game is replayed with the row given by each entry
+    TestCase::read("26_06_review_reverse").run_and_compare_result();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_27_record_every_mode() {
+    // a game with a given answer is recorded
+    TestCase::read("27_01_save_word").run_and_compare_game_state();
+    // every game with a manual answer is recorded
+    TestCase::read("27_02_save_manual").run_and_compare_game_state();
+    // the statistics only count the games of the given source
+    TestCase::read("27_03_stats_source").run_and_compare_game_state();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_28_state_format_version() {
+    // games of a file without a version are kept, and given their outcome
+    TestCase::read("28_01_migrate_v0").run_and_compare_game_state();
+    // a file newer than the program is refused
+    TestCase::read("28_02_newer_version").run_and_expect_exit();
+    // a file can still be written for older programs
+    TestCase::read("28_03_write_v0").run_and_compare_game_state();
+    // but a newer file is not downgraded
+    TestCase::read("28_04_refuse_downgrade").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_30_player_profiles() {
+    // the game is recorded under the player, and only the games of the player are counted
+    TestCase::read("30_01_player").run_and_compare_game_state();
+    // the statistics of every player, then of the games without one
+    TestCase::read("30_02_players").run_and_compare_result();
+    // of the games of a source only
+    TestCase::read("30_03_players_source").run_and_compare_result();
+    // a name must be a single word, so that it cannot be taken for another column
+    TestCase::read("30_04_invalid_player").run_and_expect_exit();
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+#[timeout(5000)]
+fn test_29_sqlite_database() {
+    let _ = std::fs::remove_file("tests/cases/29_db.run.sqlite");
+    // the games of a state file are imported
+    TestCase::read("29_01_db_import").run_and_compare_result();
+    // games are recorded in the database, and its history counted in the statistics
+    TestCase::read("29_02_db_play").run_and_compare_result();
+    // the statistics of every date, the imported games having none
+    TestCase::read("29_03_db_stats").run_and_compare_result();
+    // the games of the database can be reviewed
+    TestCase::read("29_04_db_review").run_and_compare_result();
+    // only the games of the player are counted
+    TestCase::read("29_05_db_player").run_and_compare_result();
+    // the games imported before are skipped when importing the state file again
+    TestCase::read("29_06_db_import_again").run_and_compare_result();
+}
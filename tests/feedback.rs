@@ -0,0 +1,59 @@
+use wordle::{evaluate, evaluate_union, Feedback, KeyboardState, TileState};
+
+#[test]
+fn test_evaluate_basic() {
+    assert_eq!(evaluate("crane", "crane").to_string(), "GGGGG");
+    assert_eq!(evaluate("cargo", "hello").to_string(), "RRRRG");
+    assert_eq!(evaluate("world", "audio").to_string(), "RRYRY");
+    assert!(evaluate("crane", "crane").is_solved());
+}
+
+#[test]
+fn test_evaluate_repeated_letters() {
+    // only one of the two 'L's in "hello" can be matched by "world"
+    assert_eq!(evaluate("world", "hello").to_string(), "RRRGY");
+    // the green 'E' consumes the only 'E' of the answer
+    assert_eq!(evaluate("crane", "geese").to_string(), "RRRRG");
+}
+
+#[test]
+fn test_feedback_parse() {
+    let feedback = Feedback::parse("rRyGr").unwrap();
+    assert_eq!(feedback.tiles()[2], TileState::Yellow);
+    assert_eq!(feedback.to_string(), "RRYGR");
+    assert!(Feedback::parse("RRXGR").is_none());
+}
+
+#[test]
+fn test_keyboard_state() {
+    let mut keyboard = KeyboardState::new();
+    keyboard.update("audio", &evaluate("world", "audio"));
+    keyboard.update("world", &evaluate("world", "world"));
+    keyboard.update("crane", &evaluate("world", "crane"));
+    assert_eq!(keyboard.get('o'), Some(TileState::Green));
+    assert_eq!(keyboard.get('a'), Some(TileState::Red));
+    assert_eq!(keyboard.get('z'), None);
+    assert_eq!(keyboard.to_string(), "RXRGRXXXRXXGXRGXXGXXRXGXXX");
+}
+
+#[test]
+fn test_keyboard_state_merge() {
+    let mut keyboard = KeyboardState::new();
+    keyboard.update("bake", &evaluate("able", "bake"));
+    let mut other = KeyboardState::new();
+    other.update("bake", &evaluate("lake", "bake"));
+    keyboard.merge(&other);
+    // each letter keeps its best state over both boards
+    assert_eq!(keyboard.get('a'), Some(TileState::Green));
+    assert_eq!(keyboard.get('b'), Some(TileState::Yellow));
+    assert_eq!(keyboard.get('k'), Some(TileState::Green));
+}
+
+#[test]
+fn test_evaluate_union() {
+    // a tile is green or yellow when it matches either answer
+    assert_eq!(evaluate_union(&["crane", "moist"], "audio").to_string(), "YRRYY");
+    assert_eq!(evaluate_union(&["crane", "moist"], "mrist").to_string(), "GGGGG");
+    // with a single answer, the scoring is the usual one
+    assert_eq!(evaluate_union(&["world"], "hello"), evaluate("world", "hello"));
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "sqlite")]
+use std::fs;
+use wordle::game_db::{GameDb, GameRow, Grouping, SurvivalRow};
+
+// A database of its own for every test, in the temporary directory.
+fn db_path(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("wordle-{}-{}.sqlite", std::process::id(), name));
+    let path = path.to_str().unwrap().to_string();
+    let _ = fs::remove_file(&path);
+    path
+}
+
+fn row(player: Option<&str>, date: &str, outcome: &str, tries: usize) -> GameRow {
+    GameRow {
+        player: player.map(str::to_string),
+        date: Some(date.to_string()),
+        outcome: outcome.to_string(),
+        source: "random".to_string(),
+        tries,
+        data: format!("{{\"tries\":{}}}", tries),
+    }
+}
+
+#[test]
+fn test_append_keeps_games_in_order() {
+    let path = db_path("append");
+    let mut db = GameDb::open(&path).unwrap();
+    assert_eq!(db.total_rounds().unwrap(), 0);
+    db.append(&[row(None, "2022-01-01", "won", 3), row(None, "2022-01-01", "lost", 6)], 2).unwrap();
+    db.append(&[row(None, "2022-01-02", "won", 4)], 1).unwrap();
+    // the games are kept after reopening
+    let db = GameDb::open(&path).unwrap();
+    assert_eq!(db.total_rounds().unwrap(), 3);
+    assert_eq!(db.games_of(None, None).unwrap(), ["{\"tries\":3}", "{\"tries\":6}", "{\"tries\":4}"]);
+}
+
+#[test]
+fn test_import_skips_the_games_already_there() {
+    let mut db = GameDb::open(&db_path("import")).unwrap();
+    let games = [row(None, "2022-01-01", "won", 3), row(None, "2022-01-01", "won", 3), row(None, "2022-01-01", "lost", 6)];
+    assert_eq!(db.import(&games[..2], 2).unwrap(), 2);
+    // only the copies of a game not there yet are appended, and only their rounds counted
+    assert_eq!(db.import(&games, 4).unwrap(), 1);
+    assert_eq!(db.import(&games, 4).unwrap(), 0);
+    assert_eq!(db.total_rounds().unwrap(), 3);
+    assert_eq!(db.games_of(None, None).unwrap(), ["{\"tries\":3}", "{\"tries\":3}", "{\"tries\":6}"]);
+}
+
+#[test]
+fn test_stats_by_player_and_date() {
+    let mut db = GameDb::open(&db_path("stats")).unwrap();
+    db.append(&[
+        row(Some("bob"), "2022-01-01", "won", 3),
+        row(Some("alice"), "2022-01-01", "won", 4),
+        row(Some("bob"), "2022-01-02", "lost", 6),
+        row(Some("bob"), "2022-01-02", "won", 5),
+        // neither won nor lost
+        row(None, "2022-01-02", "survived", 9),
+    ], 5).unwrap();
+
+    let players = db.stats(Grouping::Player, None).unwrap();
+    let keys: Vec<Option<&str>> = players.iter().map(|stats| stats.key.as_deref()).collect();
+    assert_eq!(keys, [None, Some("alice"), Some("bob")]);
+    assert_eq!((players[0].games, players[0].wins, players[0].losses), (1, 0, 0));
+    assert_eq!((players[2].games, players[2].wins, players[2].losses), (3, 2, 1));
+    assert_eq!(players[2].average_tries(), 4.0);
+
+    let date = db.stats(Grouping::Date, Some("2022-01-02")).unwrap();
+    assert_eq!(date.len(), 1);
+    assert_eq!((date[0].games, date[0].wins, date[0].losses, date[0].tries_won), (3, 1, 1, 5));
+    assert!(db.stats(Grouping::Player, Some("carol")).unwrap().is_empty());
+}
+
+#[test]
+fn test_set_survival_replaces_the_runs() {
+    let mut db = GameDb::open(&db_path("survival")).unwrap();
+    let run = |score| SurvivalRow { rule: "carry".to_string(), score, max_tries: 6 };
+    db.set_survival(&[run(3), run(1)]).unwrap();
+    db.set_survival(&[run(5), run(3)]).unwrap();
+    assert_eq!(db.survival().unwrap(), [run(5), run(3)]);
+}
+
+#[test]
+fn test_games_of_a_player_and_source() {
+    let mut db = GameDb::open(&db_path("games_of")).unwrap();
+    let daily = GameRow { source: "daily".to_string(), data: "{\"date\":\"2022-01-02\"}".to_string(), ..row(Some("bob"), "2022-01-02", "won", 2) };
+    db.append(&[row(Some("bob"), "2022-01-01", "won", 3), row(Some("alice"), "2022-01-01", "won", 4), daily], 3).unwrap();
+    assert_eq!(db.games_of(Some("bob"), None).unwrap(), ["{\"tries\":3}", "{\"date\":\"2022-01-02\"}"]);
+    assert_eq!(db.games_of(None, Some("random")).unwrap(), ["{\"tries\":3}", "{\"tries\":4}"]);
+    assert_eq!(db.games_of(Some("bob"), Some("daily")).unwrap(), ["{\"date\":\"2022-01-02\"}"]);
+    // only the daily puzzle of the date counts, not the other games played that day
+    assert!(db.has_daily("2022-01-02", Some("bob")).unwrap());
+    assert!(db.has_daily("2022-01-02", None).unwrap());
+    assert!(!db.has_daily("2022-01-02", Some("alice")).unwrap());
+    assert!(!db.has_daily("2022-01-01", None).unwrap());
+}
@@ -0,0 +1,4 @@
+// To build a word list from string literals.
+pub fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|word| word.to_string()).collect()
+}
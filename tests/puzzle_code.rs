@@ -0,0 +1,37 @@
+use wordle::puzzle_code::PuzzleCode;
+use wordle::Difficulty;
+
+#[test]
+fn test_codes_round_trip() {
+    for puzzle in [
+        PuzzleCode::new("crane", Difficulty::Normal, 6),
+        PuzzleCode::new("able", Difficulty::Hard, 4),
+        PuzzleCode::new("abbreviation", Difficulty::UltraHard, 0),
+    ] {
+        let code = puzzle.encode().unwrap();
+        assert!(!code.to_lowercase().contains(&puzzle.answer));
+        assert_eq!(PuzzleCode::decode(&code).unwrap(), puzzle);
+        // case, spaces and dashes are ignored
+        assert_eq!(PuzzleCode::decode(&format!(" {}-", code.to_lowercase())).unwrap(), puzzle);
+    }
+}
+
+#[test]
+fn test_similar_puzzles_have_different_codes() {
+    let crane = PuzzleCode::new("crane", Difficulty::Normal, 6).encode().unwrap();
+    let crate_ = PuzzleCode::new("crate", Difficulty::Normal, 6).encode().unwrap();
+    assert_ne!(crane[..4], crate_[..4]);
+}
+
+#[test]
+fn test_invalid_codes_are_rejected() {
+    assert!(PuzzleCode::decode("").is_err());
+    assert!(PuzzleCode::decode("ABCDEFGH").is_err());
+    assert!(PuzzleCode::decode("I0I0I0I0").is_err());
+    let mut code = PuzzleCode::new("crane", Difficulty::Normal, 6).encode().unwrap();
+    // a changed letter breaks the checksum
+    let changed = if &code[3..4] == "A" { "B" } else { "A" };
+    code.replace_range(3..4, changed);
+    assert!(PuzzleCode::decode(&code).is_err());
+    assert!(PuzzleCode::new("crane", Difficulty::Normal, 100_000).encode().is_err());
+}
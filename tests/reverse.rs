@@ -0,0 +1,43 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use wordle::reverse::{generate, solutions, unsolvable_rows, Puzzle};
+use wordle::Feedback;
+
+mod helpers;
+use helpers::words;
+
+fn rows(list: &[&str]) -> Vec<Feedback> {
+    list.iter().map(|row| Feedback::parse(row).unwrap()).collect()
+}
+
+#[test]
+fn test_solutions_and_unsolvable_rows() {
+    let acceptable = words(&["crane", "crate", "slate", "moist"]);
+    assert_eq!(solutions("crane", &Feedback::parse("GGGRG").unwrap(), &acceptable), words(&["crate"]));
+    // no word has all its letters in the wrong places
+    assert_eq!(unsolvable_rows("crane", &rows(&["GGGRG", "YYYYY", "RRRRR"]), &acceptable), vec![1]);
+}
+
+#[test]
+fn test_generated_rows_are_solvable() {
+    let acceptable = words(&["crane", "crate", "slate", "moist", "bulky", "caner"]);
+    let generated = generate("crane", &acceptable, 3, &mut StdRng::seed_from_u64(7));
+    assert_eq!(generated.len(), 3);
+    assert!(unsolvable_rows("crane", &generated, &acceptable).is_empty());
+    assert!(generated.iter().all(|row| !row.is_solved()));
+    // the same seed picks the same rows
+    assert_eq!(generate("crane", &acceptable, 3, &mut StdRng::seed_from_u64(7)), generated);
+}
+
+#[test]
+fn test_puzzle_moves_on_when_the_row_is_given() {
+    let mut puzzle = Puzzle::new("crane", rows(&["RRRRR", "GGGRG"]), 2);
+    assert_eq!(puzzle.play("crate").to_string(), "GGGRG");
+    assert_eq!(puzzle.mistakes(), 1);
+    assert_eq!(puzzle.target(), Some(&Feedback::parse("RRRRR").unwrap()));
+    puzzle.play("moist");
+    puzzle.play("crate");
+    assert!(puzzle.is_won());
+    assert_eq!(puzzle.solved(), words(&["moist", "crate"]));
+    assert_eq!(puzzle.tries_left(), Some(1));
+}
@@ -0,0 +1,88 @@
+use wordle::round::{GuessError, Round};
+use wordle::{evaluate, Difficulty, Violation};
+
+mod helpers;
+use helpers::words;
+
+#[test]
+fn test_round_checks_and_plays_guesses() {
+    let acceptable = words(&["bake", "cake", "lake"]);
+    let mut round = Round::new("lake", Difficulty::Hard, 2);
+    assert_eq!(round.check("baker", &acceptable), Err(GuessError::WrongLength(4)));
+    assert_eq!(round.check("make", &acceptable), Err(GuessError::NotInDictionary));
+    assert_eq!(round.play("bake").to_string(), "RGGG");
+    assert_eq!(round.tries_left(), Some(1));
+    assert!(matches!(round.check("able", &words(&["able"])), Err(GuessError::Violation(Violation::MissingGreen { .. }))));
+    round.play("cake");
+    assert!(round.is_over());
+    assert!(!round.is_won());
+}
+
+#[test]
+fn test_absurdle_keeps_the_largest_bucket() {
+    let answers = words(&["able", "bake", "cake", "lake"]);
+    let mut round = Round::adversarial(&answers, Difficulty::Normal, 0);
+    // "bake", "cake" and "lake" all score RGGG, leaving the largest bucket
+    assert_eq!(round.play("bake").to_string(), "RGGG");
+    assert!(!round.is_over());
+    // two buckets of one word: the one revealing fewer greens is kept
+    assert_eq!(round.play("lake").to_string(), "RGGG");
+    assert_eq!(round.answer(), "cake");
+    assert_eq!(round.play("cake").to_string(), "GGGG");
+    assert!(round.is_won());
+}
+
+#[test]
+fn test_absurdle_breaks_ties_by_fewer_yellows() {
+    let answers = words(&["able", "bake", "cake", "lake"]);
+    let mut round = Round::adversarial(&answers, Difficulty::Normal, 6);
+    // every bucket holds one word and "cake" scores the fewest yellows
+    assert_eq!(round.play("able").to_string(), "YRRG");
+    assert_eq!(round.answer(), "cake");
+}
+
+#[test]
+fn test_fibble_lies_once_per_row() {
+    let mut round = Round::fibble("stack", Difficulty::Normal, 6, 7);
+    for guess in ["crane", "moist", "slack"] {
+        let truth = evaluate("stack", guess);
+        let shown = round.play(guess).clone();
+        let wrong: Vec<usize> = (0..5).filter(|i| truth.tiles()[*i] != shown.tiles()[*i]).collect();
+        assert_eq!(wrong.len(), 1);
+        assert_eq!(round.lies().last(), Some(&wrong[0]));
+        assert!(!shown.is_solved());
+    }
+    // the row guessing the answer does not lie
+    assert!(round.play("stack").is_solved());
+    assert!(round.is_won());
+    assert_eq!(round.lies().len(), 3);
+    // the lies only depend on the seed
+    let mut again = Round::fibble("stack", Difficulty::Normal, 6, 7);
+    again.play("crane");
+    assert_eq!(again.history()[0], round.history()[0]);
+}
+
+#[test]
+fn test_xordle_needs_both_answers() {
+    let mut round = Round::xordle("crane", "moist", Difficulty::Normal, 3);
+    assert_eq!(round.answers(), vec!["crane", "moist"]);
+    assert_eq!(round.play("crane").to_string(), "GGGGG");
+    assert!(!round.is_won());
+    round.play("moist");
+    assert!(round.is_won());
+    assert!(round.is_over());
+}
+
+#[test]
+fn test_anti_keeps_to_the_hints_until_the_answer() {
+    let acceptable = words(&["crane", "crate", "slate"]);
+    let mut round = Round::anti("crane");
+    assert_eq!(round.tries_left(), None);
+    assert_eq!(round.play("crate").to_string(), "GGGRG");
+    assert_eq!(round.check("slate", &acceptable), Err(GuessError::Violation(Violation::MissingGreen { position: 0, letter: 'c' })));
+    assert_eq!(round.check("crate", &acceptable), Err(GuessError::Violation(Violation::GreyLetter { letter: 't', count: 0 })));
+    assert!(!round.is_over());
+    round.play("crane");
+    assert!(round.is_over());
+    assert_eq!(round.survived(), 1);
+}
@@ -0,0 +1,76 @@
+use wordle::solver::{expected_information, filter_candidates, filter_candidates_with_lies, hints, parse_hint, parse_history, rank_guesses, DEFAULT_HINTS};
+use wordle::Feedback;
+
+mod helpers;
+use helpers::words;
+
+#[test]
+fn test_parse_history() {
+    let history = parse_history("CRANE:rryGR, moist:RGRRR").unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].0, "crane");
+    assert_eq!(history[0].1, Feedback::parse("RRYGR").unwrap());
+    assert!(parse_history("crane").is_err());
+    assert!(parse_history("crane:RRYG").is_err());
+    assert!(parse_history("").unwrap().is_empty());
+}
+
+#[test]
+fn test_filter_candidates() {
+    let answers = words(&["cigar", "rebut", "sissy", "humph", "awake"]);
+    let history = parse_history("crane:GYYRR").unwrap();
+    assert_eq!(filter_candidates(&answers, &history), words(&["cigar"]));
+}
+
+#[test]
+fn test_filter_candidates_with_lies() {
+    let answers = words(&["bake", "cake", "lake", "make", "able"]);
+    let history = parse_history("bake:YGGG").unwrap();
+    // the first tile lies for the words ending in "ake", "bake" itself would have been shown all green
+    assert_eq!(filter_candidates_with_lies(&answers, &history), words(&["cake", "lake", "make"]));
+    assert!(filter_candidates_with_lies(&answers, &parse_history("bake:RGGG").unwrap()).is_empty());
+}
+
+#[test]
+fn test_rank_guesses() {
+    let candidates = words(&["bake", "cake", "lake", "make"]);
+    // "bcml" would tell every candidate apart, but is not a candidate itself
+    assert_eq!(expected_information("bcml", &candidates), 2.0);
+    // "bake" only tells itself apart from the other three
+    assert!((expected_information("bake", &candidates) - 0.811).abs() < 1e-3);
+    let ranked = rank_guesses(&words(&["lake", "bcml", "bake"]), &candidates);
+    let order: Vec<&str> = ranked.iter().map(|guess| guess.word.as_str()).collect();
+    assert_eq!(order, vec!["bcml", "bake", "lake"]);
+    assert!(ranked[1].candidate);
+}
+
+#[test]
+fn test_parse_hint() {
+    assert_eq!(parse_hint("hint\n"), Some(DEFAULT_HINTS));
+    assert_eq!(parse_hint(" HINT 3 "), Some(3));
+    assert_eq!(parse_hint("hint many"), None);
+    assert_eq!(parse_hint("hint 3 4"), None);
+    assert_eq!(parse_hint("hints"), None);
+    assert_eq!(parse_hint("crane"), None);
+}
+
+#[test]
+fn test_hints_split_answers_and_probes() {
+    let candidates = words(&["bake", "cake", "lake", "make"]);
+    let guesses = words(&["able", "bake", "bike", "cake", "hint", "lake", "make", "clmb"]);
+    let all = hints(&candidates, &guesses, 10);
+    let names = |list: &[wordle::solver::RankedGuess]| list.iter().map(|guess| guess.word.clone()).collect::<Vec<String>>();
+    // every possible answer, ranked then in alphabetical order
+    assert_eq!(names(&all.answers), candidates);
+    assert!(all.answers.iter().all(|guess| guess.candidate));
+    // only the other words telling the answers apart better than the best of them, "bike" and "hint" do not
+    assert_eq!(names(&all.probes), ["clmb", "able"]);
+    assert!(all.probes.iter().all(|guess| !guess.candidate && guess.entropy > all.answers[0].entropy));
+
+    // the limit applies to each list
+    let limited = hints(&candidates, &guesses, 1);
+    assert_eq!(names(&limited.answers), ["bake"]);
+    assert_eq!(names(&limited.probes), ["clmb"]);
+    // without better words, there is no probe
+    assert!(hints(&candidates, &candidates, 10).probes.is_empty());
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::thread;
+use wordle::state_file;
+
+type State = BTreeMap<String, u64>;
+
+// A state file of its own for every test, in the temporary directory.
+fn state_path(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("wordle-{}-{}.json", std::process::id(), name));
+    let path = path.to_str().unwrap().to_string();
+    for extension in ["", ".bak", ".tmp", ".lock"] {
+        let _ = fs::remove_file(format!("{}{}", path, extension));
+    }
+    path
+}
+
+fn state(count: u64) -> State {
+    BTreeMap::from([("count".to_string(), count)])
+}
+
+#[test]
+fn test_save_keeps_a_backup() {
+    let path = state_path("backup");
+    assert!(state_file::load::<State>(&path).is_err());
+    state_file::save(&path, &state(1)).unwrap();
+    state_file::save(&path, &state(2)).unwrap();
+    assert_eq!(state_file::load::<State>(&path).unwrap(), (state(2), false));
+    assert!(fs::metadata(format!("{}.tmp", path)).is_err());
+    // the previous content is the backup
+    let backup: State = serde_json::from_str(&fs::read_to_string(format!("{}.bak", path)).unwrap()).unwrap();
+    assert_eq!(backup, state(1));
+}
+
+#[test]
+fn test_damaged_state_is_recovered_from_the_backup() {
+    let path = state_path("damaged");
+    state_file::save(&path, &state(1)).unwrap();
+    state_file::save(&path, &state(2)).unwrap();
+    fs::write(&path, "{\"count\": ").unwrap();
+    assert_eq!(state_file::load::<State>(&path).unwrap(), (state(1), true));
+    // a damaged file never replaces a good backup
+    state_file::save(&path, &state(3)).unwrap();
+    assert_eq!(state_file::load::<State>(&path).unwrap(), (state(3), false));
+    fs::remove_file(&path).unwrap();
+    assert_eq!(state_file::load::<State>(&path).unwrap(), (state(1), true));
+}
+
+#[test]
+fn test_locked_updates_are_not_lost() {
+    let path = state_path("lock");
+    state_file::save(&path, &state(0)).unwrap();
+    let workers: Vec<_> = (0..8).map(|_| {
+        let path = path.clone();
+        thread::spawn(move || {
+            for _ in 0..10 {
+                let _lock = state_file::lock(&path).unwrap();
+                let (mut current, _) = state_file::load::<State>(&path).unwrap();
+                *current.get_mut("count").unwrap() += 1;
+                state_file::save(&path, &current).unwrap();
+            }
+        })
+    }).collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    assert_eq!(state_file::load::<State>(&path).unwrap(), (state(80), false));
+}
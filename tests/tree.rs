@@ -0,0 +1,43 @@
+use wordle::solver::parse_history;
+use wordle::tree::{DecisionTree, TreeExport};
+use wordle::Difficulty;
+
+mod helpers;
+use helpers::words;
+
+#[test]
+fn test_tree_solves_every_answer() {
+    let answers = words(&["bake", "cake", "lake", "make", "able"]);
+    let acceptable = words(&["able", "bake", "bcml", "cake", "lake", "make"]);
+    let tree = DecisionTree::build("bake", &answers, &acceptable, Difficulty::Normal);
+    for answer in &answers {
+        let guesses = tree.play(answer).unwrap();
+        assert_eq!(guesses.last().unwrap(), answer);
+    }
+    // "bcml" tells the three words ending in "ake" apart
+    assert_eq!(tree.play("make").unwrap(), words(&["bake", "bcml", "make"]));
+    let export = TreeExport::new(tree, &answers, Difficulty::Normal).unwrap();
+    assert_eq!(export.words, 5);
+    assert_eq!(export.worst, 3);
+    assert!((export.average - 2.4).abs() < 1e-9);
+}
+
+#[test]
+fn test_tree_in_difficult_mode() {
+    let answers = words(&["bake", "cake", "lake", "make", "able"]);
+    let acceptable = words(&["able", "bake", "bcml", "cake", "lake", "make"]);
+    let tree = DecisionTree::build("bake", &answers, &acceptable, Difficulty::Hard);
+    // "bcml" does not use the revealed hints
+    assert!(tree.play("make").unwrap().iter().all(|guess| guess != "bcml"));
+}
+
+#[test]
+fn test_tree_recommendations() {
+    let answers = words(&["bake", "cake", "lake"]);
+    let tree = DecisionTree::build("bake", &answers, &answers, Difficulty::Normal);
+    let history = parse_history("bake:RGGG").unwrap();
+    assert_eq!(tree.recommendations(&history), words(&["bake", "cake"]));
+    // the player left the tree with the first guess
+    let history = parse_history("lake:RGGG,bake:RGGG").unwrap();
+    assert_eq!(tree.recommendations(&history), words(&["bake"]));
+}
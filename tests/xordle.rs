@@ -0,0 +1,23 @@
+use wordle::solver::parse_history;
+use wordle::xordle::{disjoint_pairs, filter_pairs};
+
+mod helpers;
+use helpers::words;
+
+#[test]
+fn test_disjoint_pairs() {
+    let answers = words(&["crane", "moist", "bulky", "music"]);
+    let pairs = disjoint_pairs(&answers);
+    let pairs: Vec<(&str, &str)> = pairs.iter().map(|(a, b)| (a.as_str(), b.as_str())).collect();
+    // "music" shares letters with every other word
+    assert_eq!(pairs, vec![("crane", "moist"), ("crane", "bulky"), ("moist", "bulky")]);
+}
+
+#[test]
+fn test_filter_pairs() {
+    let pairs = disjoint_pairs(&words(&["crane", "moist", "bulky"]));
+    // "crane" is one of the answers, and no letter of "bulky" is in the other one
+    let history = parse_history("crane:GGGGG,bulky:RRRRR").unwrap();
+    let left = filter_pairs(&pairs, &history);
+    assert_eq!(left, vec![("crane".to_string(), "moist".to_string())]);
+}